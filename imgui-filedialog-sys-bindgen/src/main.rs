@@ -44,6 +44,6 @@ fn main() {
 
     let out_path = sys_crate_path.join("src");
     bindings
-        .write_to_file(&out_path.join("bindings.rs"))
+        .write_to_file(out_path.join("bindings.rs"))
         .expect("Couldn't write bindings!");
 }
@@ -5,7 +5,10 @@
 // for now, but expected to diverge from that over time.
 use std::{env, fs, io, path::Path};
 
-const CPP_FILES: &[&str] = &["third-party/ImGuiFileDialog/ImGuiFileDialog.cpp"];
+const CPP_FILES: &[&str] = &[
+    "third-party/ImGuiFileDialog/ImGuiFileDialog.cpp",
+    "ext/ImGuiFileDialogExt.cpp",
+];
 
 fn assert_file_exists(path: &str) -> io::Result<()> {
     match fs::metadata(path) {
@@ -29,7 +32,7 @@ fn main() -> io::Result<()> {
     env::vars()
         .filter_map(|(key, val)| {
             key.strip_prefix("DEP_IMGUI_DEFINE_")
-                .and_then(|suffix| Some((suffix.to_string(), val.to_string())))
+                .map(|suffix| (suffix.to_string(), val.to_string()))
         })
         .for_each(|(key, value)| {
             build.define(&key, value.as_str());
@@ -39,6 +42,10 @@ fn main() -> io::Result<()> {
         assert_file_exists(path)?;
         build.file(path);
     }
+    // cc prints rerun-if-env-changed, which turns off cargo's default of rerunning on any
+    // change in the package, so list the sources explicitly
+    println!("cargo:rerun-if-changed=third-party/ImGuiFileDialog");
+    println!("cargo:rerun-if-changed=ext");
 
     let cimgui_include_path =
         env::var_os("DEP_IMGUI_THIRD_PARTY").expect("DEP_IMGUI_THIRD_PARTY not defined");
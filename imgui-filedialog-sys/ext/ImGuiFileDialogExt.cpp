@@ -0,0 +1,157 @@
+#include "ImGuiFileDialogExt.h"
+
+#include <imgui.h>
+#include <imgui_internal.h>
+
+namespace {
+constexpr bool StringsEqual(const char* a, const char* b) {
+    return *a == *b && (*a == '\0' || StringsEqual(a + 1, b + 1));
+}
+
+// the shims below reach into ImGuiFileDialog's internals (sorting fields, filtered
+// listing, selection), which change between releases: check them when updating
+static_assert(StringsEqual(IGFD_VERSION, "v0.6.8"), "ImGuiFileDialogExt is written against ImGuiFileDialog v0.6.8");
+
+// FileDialog keeps its state protected; a pointer to member named through a derived
+// class reaches it without relying on the object layout
+struct InternalAccess : IGFD::FileDialog {
+    static IGFD::FileDialogInternal& Get(ImGuiFileDialog* vContextPtr) {
+        return vContextPtr->*(&InternalAccess::m_FileDialogInternal);
+    }
+};
+}  // namespace
+
+IGFD_EXT_C_API bool IGFD_Ext_IsWindowFocused(const char* vWindowName) {
+    ImGuiContext* ctx = ImGui::GetCurrentContext();
+    if (ctx == nullptr || vWindowName == nullptr) {
+        return false;
+    }
+    ImGuiWindow* window = ImGui::FindWindowByName(vWindowName);
+    ImGuiWindow* focused = ctx->NavWindow;
+    return window != nullptr && focused != nullptr && focused->RootWindow == window->RootWindow;
+}
+
+IGFD_EXT_C_API bool IGFD_Ext_IsWindowVisible(const char* vWindowName) {
+    if (ImGui::GetCurrentContext() == nullptr || vWindowName == nullptr) {
+        return false;
+    }
+    ImGuiWindow* window = ImGui::FindWindowByName(vWindowName);
+    // Active is reset on NewFrame, WasActive covers queries made before this frame's Begin
+    return window != nullptr && (window->Active || window->WasActive) && !window->Collapsed && !window->Hidden;
+}
+
+IGFD_EXT_C_API bool IGFD_Ext_GetWindowRect(const char* vWindowName, ImVec2* vOutMin, ImVec2* vOutMax) {
+    if (ImGui::GetCurrentContext() == nullptr || vWindowName == nullptr) {
+        return false;
+    }
+    ImGuiWindow* window = ImGui::FindWindowByName(vWindowName);
+    if (window == nullptr || !(window->Active || window->WasActive) || window->Hidden) {
+        return false;
+    }
+    if (vOutMin != nullptr) {
+        *vOutMin = window->Pos;
+    }
+    if (vOutMax != nullptr) {
+        *vOutMax = ImVec2(window->Pos.x + window->Size.x, window->Pos.y + window->Size.y);
+    }
+    return true;
+}
+
+IGFD_EXT_C_API bool IGFD_Ext_GetSorting(ImGuiFileDialog* vContextPtr, int* vOutField, bool* vOutAscending) {
+    if (vContextPtr == nullptr) {
+        return false;
+    }
+    const auto& fileManager = InternalAccess::Get(vContextPtr).fileManager;
+    const auto field        = static_cast<int>(fileManager.sortingField);
+    if (field <= 0) {
+        return false;
+    }
+    if (vOutField != nullptr) {
+        *vOutField = field;
+    }
+    if (vOutAscending != nullptr) {
+        *vOutAscending = fileManager.sortingDirection[field - 1];
+    }
+    return true;
+}
+
+IGFD_EXT_C_API void IGFD_Ext_SetSorting(ImGuiFileDialog* vContextPtr, int vField, bool vAscending) {
+    // thumbnails (5) only exist with USE_THUMBNAILS, which isn't enabled
+    if (vContextPtr == nullptr || vField < 1 || vField > 4) {
+        return;
+    }
+    auto& internal                           = InternalAccess::Get(vContextPtr);
+    auto& fileManager                        = internal.fileManager;
+    fileManager.sortingField                 = static_cast<IGFD::FileManager::SortingFieldEnum>(vField);
+    fileManager.sortingDirection[vField - 1] = vAscending;
+    fileManager.SortFields(internal);
+}
+
+IGFD_EXT_C_API bool IGFD_Ext_GetSelectedIndex(ImGuiFileDialog* vContextPtr, size_t* vOutIndex) {
+    if (vContextPtr == nullptr) {
+        return false;
+    }
+    auto& fileManager = InternalAccess::Get(vContextPtr).fileManager;
+    // the filtered list holds the rows as displayed: searched, filtered and sorted
+    for (size_t i = 0; i < fileManager.GetFilteredListSize(); ++i) {
+        const auto infos = fileManager.GetFilteredFileAt(i);
+        if (infos != nullptr && fileManager.IsFileNameSelected(infos->fileNameExt)) {
+            if (vOutIndex != nullptr) {
+                *vOutIndex = i;
+            }
+            return true;
+        }
+    }
+    return false;
+}
+
+IGFD_EXT_C_API const char* IGFD_Ext_GetListedFileName(ImGuiFileDialog* vContextPtr, size_t vIndex) {
+    if (vContextPtr == nullptr) {
+        return nullptr;
+    }
+    auto& fileManager = InternalAccess::Get(vContextPtr).fileManager;
+    if (vIndex >= fileManager.GetFilteredListSize()) {
+        return nullptr;
+    }
+    const auto infos = fileManager.GetFilteredFileAt(vIndex);
+    // the listing keeps the entry alive, the name is valid until it's scanned or sorted again
+    return infos != nullptr ? infos->fileNameExt.c_str() : "";
+}
+
+IGFD_EXT_C_API void IGFD_Ext_Refresh(ImGuiFileDialog* vContextPtr) {
+    if (vContextPtr == nullptr) {
+        return;
+    }
+    auto& internal = InternalAccess::Get(vContextPtr);
+    // unlike OpenCurrentPath, ScanDir leaves the file name field and the path bar alone
+    internal.fileManager.ScanDir(internal, internal.fileManager.GetCurrentPath());
+}
+
+IGFD_EXT_C_API void IGFD_Ext_OpenDialogWithEntryFilter(ImGuiFileDialog* vContextPtr,
+                                                       const char* vKey,
+                                                       const char* vTitle,
+                                                       const char* vFilters,
+                                                       const IGFD_FileDialog_Config vConfig,
+                                                       IGFD_Ext_EntryFilterFun vEntryFilter,
+                                                       void* vEntryFilterUserDatas) {
+    if (vContextPtr == nullptr) {
+        return;
+    }
+    // same as IGFD_OpenDialog, plus the userFileAttributes hook
+    IGFD::FileDialogConfig config;
+    config.path              = vConfig.path;
+    config.fileName          = vConfig.fileName;
+    config.filePathName      = vConfig.filePathName;
+    config.countSelectionMax = vConfig.countSelectionMax;
+    config.userDatas         = vConfig.userDatas;
+    config.flags             = vConfig.flags;
+    config.sidePane          = vConfig.sidePane;
+    config.sidePaneWidth     = vConfig.sidePaneWidth;
+    if (vEntryFilter != nullptr) {
+        config.userFileAttributes = [vEntryFilter, vEntryFilterUserDatas](IGFD::FileInfos* vInfos, IGFD::UserDatas) {
+            // directories stay visible so the user can still navigate
+            return vInfos->fileType.isDir() || vEntryFilter(vInfos->filePath.c_str(), vInfos->fileNameExt.c_str(), vEntryFilterUserDatas);
+        };
+    }
+    vContextPtr->OpenDialog(vKey, vTitle, vFilters, config);
+}
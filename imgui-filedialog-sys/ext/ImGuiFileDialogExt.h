@@ -0,0 +1,57 @@
+// Helpers exposing ImGui window state of a file dialog by its window name
+// ("<title>##<key>"), which the public ImGui API can only query for the current window,
+// and ImGuiFileDialog features its C API leaves out.
+
+#pragma once
+
+#include "../third-party/ImGuiFileDialog/ImGuiFileDialog.h"
+
+#ifdef __cplusplus
+#define IGFD_EXT_C_API extern "C"
+#else
+#define IGFD_EXT_C_API
+#include <stdbool.h>
+#endif
+
+IGFD_EXT_C_API bool IGFD_Ext_IsWindowFocused(  // true if the window or one of its children has focus
+    const char* vWindowName);                  // ImGui window name
+
+IGFD_EXT_C_API bool IGFD_Ext_IsWindowVisible(  // true if the window was submitted this or last frame and isn't collapsed
+    const char* vWindowName);                  // ImGui window name
+
+IGFD_EXT_C_API bool IGFD_Ext_GetWindowRect(  // false if the window isn't submitted, else writes its screen rect
+    const char* vWindowName,                  // ImGui window name
+    ImVec2* vOutMin,                          // top-left corner
+    ImVec2* vOutMax);                         // bottom-right corner
+
+IGFD_EXT_C_API bool IGFD_Ext_GetSorting(  // false if the listing isn't sorted, else writes the sort column and direction
+    ImGuiFileDialog* vContextPtr,          // ImGuiFileDialog context
+    int* vOutField,                        // sorting field (1 name, 2 type, 3 size, 4 date, 5 thumbnails)
+    bool* vOutAscending);                  // true if ascending
+
+IGFD_EXT_C_API void IGFD_Ext_SetSorting(  // sorts the listing like clicking a column header, ignores unknown fields
+    ImGuiFileDialog* vContextPtr,          // ImGuiFileDialog context
+    int vField,                            // sorting field (1 name, 2 type, 3 size, 4 date)
+    bool vAscending);                      // true for ascending
+
+IGFD_EXT_C_API bool IGFD_Ext_GetSelectedIndex(  // false if no listed entry is selected
+    ImGuiFileDialog* vContextPtr,                // ImGuiFileDialog context
+    size_t* vOutIndex);                          // row of the first selected entry in the displayed listing
+
+IGFD_EXT_C_API const char* IGFD_Ext_GetListedFileName(  // null past the last row, owned by the listing: copy it
+    ImGuiFileDialog* vContextPtr,                       // ImGuiFileDialog context
+    size_t vIndex);                                     // row in the displayed listing
+
+IGFD_EXT_C_API void IGFD_Ext_Refresh(  // scans the current directory again, keeping path, file name and selection
+    ImGuiFileDialog* vContextPtr);     // ImGuiFileDialog context
+
+typedef bool (*IGFD_Ext_EntryFilterFun)(const char*, const char*, void*);  // path, file name, user data
+
+IGFD_EXT_C_API void IGFD_Ext_OpenDialogWithEntryFilter(  // IGFD_OpenDialog with a filter hiding files
+    ImGuiFileDialog* vContextPtr,                       // ImGuiFileDialog context
+    const char* vKey,                                   // key dialog
+    const char* vTitle,                                 // title
+    const char* vFilters,                               // filters, null for directory mode
+    const IGFD_FileDialog_Config vConfig,               // config
+    IGFD_Ext_EntryFilterFun vEntryFilter,               // called for every file (not directory), hidden if false
+    void* vEntryFilterUserDatas);                       // passed to vEntryFilter
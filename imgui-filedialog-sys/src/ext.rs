@@ -0,0 +1,50 @@
+// Hand-written bindings to ext/ImGuiFileDialogExt.h. These helpers are not part of
+// ImGuiFileDialog, so they are kept apart from the bindgen output in bindings.rs.
+
+use crate::bindings::{size_t, IGFD_FileDialog_Config, ImGuiFileDialog, ImVec2};
+
+pub type IGFD_Ext_EntryFilterFun = ::std::option::Option<
+    unsafe extern "C" fn(
+        arg1: *const ::std::os::raw::c_char,
+        arg2: *const ::std::os::raw::c_char,
+        arg3: *mut ::std::os::raw::c_void,
+    ) -> bool,
+>;
+
+extern "C" {
+    pub fn IGFD_Ext_IsWindowFocused(vWindowName: *const ::std::os::raw::c_char) -> bool;
+    pub fn IGFD_Ext_IsWindowVisible(vWindowName: *const ::std::os::raw::c_char) -> bool;
+    pub fn IGFD_Ext_GetWindowRect(
+        vWindowName: *const ::std::os::raw::c_char,
+        vOutMin: *mut ImVec2,
+        vOutMax: *mut ImVec2,
+    ) -> bool;
+    pub fn IGFD_Ext_GetSorting(
+        vContextPtr: *mut ImGuiFileDialog,
+        vOutField: *mut ::std::os::raw::c_int,
+        vOutAscending: *mut bool,
+    ) -> bool;
+    pub fn IGFD_Ext_SetSorting(
+        vContextPtr: *mut ImGuiFileDialog,
+        vField: ::std::os::raw::c_int,
+        vAscending: bool,
+    );
+    pub fn IGFD_Ext_GetSelectedIndex(
+        vContextPtr: *mut ImGuiFileDialog,
+        vOutIndex: *mut size_t,
+    ) -> bool;
+    pub fn IGFD_Ext_GetListedFileName(
+        vContextPtr: *mut ImGuiFileDialog,
+        vIndex: size_t,
+    ) -> *const ::std::os::raw::c_char;
+    pub fn IGFD_Ext_Refresh(vContextPtr: *mut ImGuiFileDialog);
+    pub fn IGFD_Ext_OpenDialogWithEntryFilter(
+        vContextPtr: *mut ImGuiFileDialog,
+        vKey: *const ::std::os::raw::c_char,
+        vTitle: *const ::std::os::raw::c_char,
+        vFilters: *const ::std::os::raw::c_char,
+        vConfig: IGFD_FileDialog_Config,
+        vEntryFilter: IGFD_Ext_EntryFilterFun,
+        vEntryFilterUserDatas: *mut ::std::os::raw::c_void,
+    );
+}
@@ -5,5 +5,10 @@
 // just to link it for tests
 use imgui_sys as _;
 
+// the generated layout tests dereference null pointers, they are not run (see Cargo.toml)
+#[allow(clippy::all, deref_nullptr)]
 mod bindings;
 pub use crate::bindings::*;
+
+mod ext;
+pub use crate::ext::*;
@@ -0,0 +1,17 @@
+// The ImGuiFileDialog functions this crate calls. With the `mock` feature they resolve to
+// the recording stand-ins in `mock::ffi` instead of the real C API.
+
+#[cfg(feature = "mock")]
+pub(crate) use crate::mock::ffi::*;
+
+#[cfg(not(feature = "mock"))]
+pub(crate) use sys::{
+    IGFD_ClearFilesStyle, IGFD_CloseDialog, IGFD_Create, IGFD_Destroy, IGFD_DisplayDialog,
+    IGFD_Ext_GetListedFileName, IGFD_Ext_GetSelectedIndex, IGFD_Ext_GetSorting,
+    IGFD_Ext_GetWindowRect, IGFD_Ext_IsWindowFocused, IGFD_Ext_IsWindowVisible,
+    IGFD_Ext_OpenDialogWithEntryFilter, IGFD_Ext_Refresh, IGFD_Ext_SetSorting,
+    IGFD_GetCurrentFileName, IGFD_GetCurrentFilter, IGFD_GetCurrentPath, IGFD_GetFilePathName,
+    IGFD_GetFileStyle, IGFD_GetSelection, IGFD_IsKeyOpened, IGFD_IsOk, IGFD_IsOpened,
+    IGFD_OpenDialog, IGFD_Selection_DestroyContent, IGFD_SetFileStyle2, IGFD_WasKeyOpenedThisFrame,
+    IGFD_WasOpenedThisFrame, SetLocales,
+};
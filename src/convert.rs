@@ -0,0 +1,46 @@
+//! Conversions between the raw [`sys`](crate::sys) vectors and the `mint` types used by
+//! this crate's API.
+//!
+//! ```
+//! use imgui_filedialog::convert::{from_imvec2, from_imvec4, to_imvec2, to_imvec4};
+//!
+//! let size = mint::Vector2 { x: 640.0, y: 480.0 };
+//! assert_eq!(from_imvec2(to_imvec2(size)), size);
+//!
+//! let color = mint::Vector4 { x: 1.0, y: 0.5, z: 0.25, w: 1.0 };
+//! assert_eq!(from_imvec4(to_imvec4(color)), color);
+//! ```
+
+use crate::{MintVec2, MintVec4};
+
+/// Convert a raw `ImVec2` to a mint vector
+pub fn from_imvec2(v: sys::ImVec2) -> MintVec2 {
+    MintVec2 { x: v.x, y: v.y }
+}
+
+/// Convert anything accepted as a size or position (e.g. `[f32; 2]`) to a raw `ImVec2`
+pub fn to_imvec2(v: impl Into<MintVec2>) -> sys::ImVec2 {
+    let v = v.into();
+    sys::ImVec2 { x: v.x, y: v.y }
+}
+
+/// Convert a raw `ImVec4` to a mint vector
+pub fn from_imvec4(v: sys::ImVec4) -> MintVec4 {
+    MintVec4 {
+        x: v.x,
+        y: v.y,
+        z: v.z,
+        w: v.w,
+    }
+}
+
+/// Convert anything accepted as a color (e.g. `[f32; 4]`) to a raw `ImVec4`
+pub fn to_imvec4(v: impl Into<MintVec4>) -> sys::ImVec4 {
+    let v = v.into();
+    sys::ImVec4 {
+        x: v.x,
+        y: v.y,
+        z: v.z,
+        w: v.w,
+    }
+}
@@ -0,0 +1,121 @@
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+/// Errors reported by the fallible file dialog APIs
+#[derive(Debug)]
+pub enum FileDialogError {
+    /// The dialog id is empty
+    EmptyId,
+    /// The dialog was displayed before ever being opened
+    NeverOpened,
+    /// ImGuiFileDialog failed to create its context
+    NullContext,
+    /// The dialog was displayed while no ImGui context is current
+    NoImGuiContext,
+    /// The filter string passed to `try_open` is malformed
+    InvalidFilters(FilterParseError),
+    /// `count_selection_max` was negative
+    InvalidSelectionMax(i32),
+    /// A color component is outside of `[0, 1]`, e.g. a 0-255 byte value passed as float
+    InvalidColor([f32; 4]),
+    /// A reflected value could not be applied
+    #[cfg(feature = "bevy_reflect")]
+    Reflect(bevy_reflect::ApplyError),
+}
+
+impl fmt::Display for FileDialogError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyId => write!(f, "dialog id must not be empty"),
+            Self::NeverOpened => write!(f, "dialog was displayed before being opened"),
+            Self::NullContext => write!(f, "ImGuiFileDialog context is null"),
+            Self::NoImGuiContext => {
+                write!(f, "dialog was displayed without a current ImGui context")
+            }
+            Self::InvalidFilters(err) => write!(f, "invalid filters: {}", err),
+            Self::InvalidSelectionMax(max) => {
+                write!(f, "invalid count_selection_max {} (must be >= 0)", max)
+            }
+            Self::InvalidColor(color) => write!(
+                f,
+                "invalid color {:?} (components must be in [0, 1], see rgba8 for byte values)",
+                color
+            ),
+            #[cfg(feature = "bevy_reflect")]
+            Self::Reflect(err) => write!(f, "could not apply reflected value: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for FileDialogError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidFilters(err) => Some(err),
+            #[cfg(feature = "bevy_reflect")]
+            Self::Reflect(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Errors preparing the result of a confirmed dialog
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DialogOutcomeError {
+    /// The parent directory of the result `path` could not be created, see
+    /// `FileDialogConfig::create_parent_dirs`
+    CreateDir {
+        /// The result path whose parent directory is missing
+        path: PathBuf,
+        /// Why the directory could not be created
+        kind: io::ErrorKind,
+    },
+}
+
+impl fmt::Display for DialogOutcomeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CreateDir { path, kind } => write!(
+                f,
+                "could not create the parent directory of {}: {}",
+                path.display(),
+                kind
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DialogOutcomeError {}
+
+/// Errors reported when validating a filter string
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FilterParseError {
+    /// The filter string contains a NUL byte
+    ContainsNul,
+    /// A filter between two commas is empty
+    EmptyFilter,
+    /// A `{` group is missing its closing `}`
+    UnterminatedGroup(String),
+    /// A `}` appears without an opening `{`, or text follows a closing `}`
+    UnexpectedBrace(String),
+    /// A filter is neither an extension starting with `.` nor a `((regex))`
+    InvalidFilter(String),
+}
+
+impl fmt::Display for FilterParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ContainsNul => write!(f, "filter string contains a NUL byte"),
+            Self::EmptyFilter => write!(f, "empty filter"),
+            Self::UnterminatedGroup(part) => write!(f, "unterminated filter group `{}`", part),
+            Self::UnexpectedBrace(part) => write!(f, "unexpected brace in `{}`", part),
+            Self::InvalidFilter(filter) => write!(
+                f,
+                "invalid filter `{}` (expected `.ext` or `((regex))`)",
+                filter
+            ),
+        }
+    }
+}
+
+impl std::error::Error for FilterParseError {}
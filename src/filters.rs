@@ -0,0 +1,348 @@
+use crate::error::FilterParseError;
+use crate::ResultMode;
+use std::fmt;
+
+/// A named group of extensions, as shown in the dialog's filter combo box
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FilterGroup {
+    /// Label of the group, as reported by `FileDialog::current_filter`
+    pub label: String,
+    /// Extensions (e.g. `.png`) or regex filters (e.g. `((.*\.png))`) of the group
+    pub extensions: Vec<String>,
+}
+
+impl FilterGroup {
+    /// Get the first plain extension of the group, skipping wildcard and regex filters.
+    ///
+    /// This is the extension the dialog appends to typed file names.
+    pub fn primary_extension(&self) -> Option<&str> {
+        self.extensions
+            .first()
+            .map(String::as_str)
+            .filter(|ext| !ext.contains('*') && !ext.contains("(("))
+    }
+
+    /// Apply a [`ResultMode`] to a file name the way the dialog does for this group.
+    ///
+    /// Groups without a [primary extension](Self::primary_extension) leave the name as is.
+    pub fn apply_result_mode(&self, file_name: &str, mode: ResultMode) -> String {
+        let ext = match self.primary_extension() {
+            Some(ext) => ext,
+            None => return file_name.to_string(),
+        };
+        // position of the dot the filter's extension would start at, if any
+        let min_dots = file_name.matches('.').count().min(ext.matches('.').count());
+        let ext_start = if min_dots == 0 {
+            None
+        } else {
+            file_name
+                .rmatch_indices('.')
+                .nth(min_dots - 1)
+                .map(|(i, _)| i)
+        };
+        let result = match mode {
+            ResultMode::KeepInputFile => return file_name.to_string(),
+            ResultMode::OverwriteFileExt => {
+                format!(
+                    "{}{}",
+                    &file_name[..ext_start.unwrap_or(file_name.len())],
+                    ext
+                )
+            }
+            ResultMode::AddIfNoFileExt => match ext_start {
+                None => format!("{}{}", file_name, ext),
+                Some(i) if i + 1 == file_name.len() => format!("{}{}", &file_name[..i], ext),
+                Some(_) => file_name.to_string(),
+            },
+        };
+        result.replace("..", ".")
+    }
+}
+
+/// Parsed form of a filter string such as `"Image files{.png,.jpg},.txt"`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Filters {
+    groups: Vec<FilterGroup>,
+}
+
+impl Filters {
+    /// Parse a filter string the same way ImGuiFileDialog does.
+    ///
+    /// Malformed parts (e.g. an unterminated `{` group or a filter not starting with `.`)
+    /// are skipped, like the dialog skips them.
+    pub fn parse(filters: &str) -> Self {
+        let groups = split_top_level(filters)
+            .into_iter()
+            .filter_map(parse_group)
+            .collect();
+        Self { groups }
+    }
+
+    /// Parse a filter string, failing on anything the dialog would silently skip.
+    pub fn from_str_checked(filters: &str) -> Result<Self, FilterParseError> {
+        if filters.contains('\0') {
+            return Err(FilterParseError::ContainsNul);
+        }
+        let mut groups = Vec::new();
+        for part in split_top_level(filters) {
+            if part.trim().is_empty() {
+                return Err(FilterParseError::EmptyFilter);
+            }
+            match (part.find('{'), part.find('}')) {
+                (Some(open), Some(close)) if close > open => {
+                    if !part[close + 1..].trim().is_empty() || part[open + 1..close].contains('{') {
+                        return Err(FilterParseError::UnexpectedBrace(part.to_string()));
+                    }
+                }
+                (Some(_), None) => {
+                    return Err(FilterParseError::UnterminatedGroup(part.to_string()));
+                }
+                (None, None) => {}
+                _ => return Err(FilterParseError::UnexpectedBrace(part.to_string())),
+            }
+
+            let group = parse_group(part)
+                .ok_or_else(|| FilterParseError::InvalidFilter(part.trim().to_string()))?;
+            if group.extensions.is_empty() {
+                return Err(FilterParseError::EmptyFilter);
+            }
+            // parse_group drops invalid entries of a collection, check for them here
+            if let (Some(open), Some(close)) = (part.find('{'), part.rfind('}')) {
+                for ext in split_top_level(&part[open + 1..close]) {
+                    let ext = ext.replace(' ', "");
+                    if !is_valid_filter(&ext) {
+                        return Err(FilterParseError::InvalidFilter(ext));
+                    }
+                }
+            }
+            groups.push(group);
+        }
+        Ok(Self { groups })
+    }
+
+    /// Add a group of extensions shown as `label`, for building filters in code.
+    ///
+    /// ImGuiFileDialog has no escape syntax, so characters it can't show in a label are
+    /// removed: `{`, `}`, and commas outside of parentheses. Commas inside parentheses are
+    /// kept, e.g. `"Images (png, jpg)"` works as is. The group's `label` holds the cleaned
+    /// up text, which is what `FileDialog::current_filter` reports.
+    pub fn with_group(mut self, label: &str, extensions: &[&str]) -> Self {
+        self.groups.push(FilterGroup {
+            label: sanitize_label(label),
+            extensions: extensions.iter().map(|ext| ext.replace(' ', "")).collect(),
+        });
+        self
+    }
+
+    /// Get the parsed filter groups in display order
+    pub fn groups(&self) -> &[FilterGroup] {
+        &self.groups
+    }
+
+    /// Find the group with the given label
+    pub fn find(&self, label: &str) -> Option<&FilterGroup> {
+        self.groups.iter().find(|group| group.label == label)
+    }
+}
+
+impl fmt::Display for Filters {
+    /// Formats the filters back into the string syntax the dialog expects.
+    ///
+    /// Labels are cleaned up like in `with_group`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, group) in self.groups.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            if group.extensions.len() == 1 && group.label == group.extensions[0] {
+                write!(f, "{}", group.label)?;
+            } else {
+                let label = sanitize_label(&group.label);
+                write!(f, "{}{{{}}}", label, group.extensions.join(","))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Split at commas that are neither inside a `{}` collection nor inside parentheses
+fn split_top_level(filters: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut braces = 0usize;
+    let mut parens = 0usize;
+    let mut start = 0;
+    for (i, c) in filters.char_indices() {
+        match c {
+            '{' => braces += 1,
+            '}' => braces = braces.saturating_sub(1),
+            '(' => parens += 1,
+            ')' => parens = parens.saturating_sub(1),
+            ',' if braces == 0 && parens == 0 => {
+                parts.push(&filters[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&filters[start..]);
+    parts
+}
+
+/// Remove the characters that would end a label early or split the filter string:
+/// `{`, `}`, and commas outside of parentheses
+fn sanitize_label(label: &str) -> String {
+    let mut parens = 0usize;
+    label
+        .chars()
+        .filter(|&c| {
+            match c {
+                '(' => parens += 1,
+                ')' => parens = parens.saturating_sub(1),
+                '{' | '}' => return false,
+                ',' => return parens > 0,
+                _ => {}
+            }
+            true
+        })
+        .collect()
+}
+
+fn is_valid_filter(filter: &str) -> bool {
+    (filter.len() > 1 && filter.starts_with('.'))
+        || (filter.starts_with("((") && filter.ends_with("))"))
+}
+
+fn parse_group(part: &str) -> Option<FilterGroup> {
+    match part.find('{') {
+        Some(open) => {
+            let close = part.rfind('}').filter(|&close| close > open)?;
+            let extensions = split_top_level(&part[open + 1..close])
+                .into_iter()
+                .map(|ext| ext.replace(' ', ""))
+                .filter(|ext| is_valid_filter(ext))
+                .collect();
+            Some(FilterGroup {
+                label: part[..open].to_string(),
+                extensions,
+            })
+        }
+        None => {
+            let filter = part.replace(' ', "");
+            if !is_valid_filter(&filter) {
+                return None;
+            }
+            Some(FilterGroup {
+                label: filter.clone(),
+                extensions: vec![filter],
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn with_group_formats_each_group() {
+        let filters = Filters::default()
+            .with_group("Images", &[".png", ".jpg"])
+            .with_group("Text", &[".txt"])
+            .with_group("Archives", &[".tar.gz", ".zip"]);
+        assert_eq!(
+            filters.to_string(),
+            "Images{.png,.jpg},Text{.txt},Archives{.tar.gz,.zip}"
+        );
+        assert_eq!(filters.groups().len(), 3);
+        assert_eq!(Filters::parse(&filters.to_string()), filters);
+    }
+
+    #[test]
+    fn labels_with_commas_round_trip() {
+        let filters = Filters::default()
+            .with_group("Images (png, jpg)", &[".png", ".jpg"])
+            .with_group("Docs, text", &[".txt"]);
+        assert_eq!(filters.groups()[0].label, "Images (png, jpg)");
+        assert_eq!(filters.groups()[1].label, "Docs text");
+
+        let formatted = filters.to_string();
+        assert_eq!(formatted, "Images (png, jpg){.png,.jpg},Docs text{.txt}");
+        assert_eq!(Filters::parse(&formatted), filters);
+        assert_eq!(Filters::from_str_checked(&formatted), Ok(filters));
+    }
+
+    fn group(extensions: &[&str]) -> FilterGroup {
+        FilterGroup {
+            label: "Files".to_string(),
+            extensions: extensions.iter().map(|ext| ext.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn apply_result_mode_with_single_extension() {
+        let png = group(&[".png"]);
+        assert_eq!(
+            png.apply_result_mode("photo.jpg", ResultMode::KeepInputFile),
+            "photo.jpg"
+        );
+        assert_eq!(
+            png.apply_result_mode("photo.jpg", ResultMode::OverwriteFileExt),
+            "photo.png"
+        );
+        assert_eq!(
+            png.apply_result_mode("photo", ResultMode::OverwriteFileExt),
+            "photo.png"
+        );
+        assert_eq!(
+            png.apply_result_mode("photo", ResultMode::AddIfNoFileExt),
+            "photo.png"
+        );
+        assert_eq!(
+            png.apply_result_mode("photo.", ResultMode::AddIfNoFileExt),
+            "photo.png"
+        );
+        assert_eq!(
+            png.apply_result_mode("photo.jpg", ResultMode::AddIfNoFileExt),
+            "photo.jpg"
+        );
+    }
+
+    #[test]
+    fn apply_result_mode_with_multiple_extensions() {
+        // the first extension of the group is the one applied
+        let archives = group(&[".tar.gz", ".zip"]);
+        assert_eq!(
+            archives.apply_result_mode("backup.zip", ResultMode::KeepInputFile),
+            "backup.zip"
+        );
+        assert_eq!(
+            archives.apply_result_mode("backup.zip", ResultMode::OverwriteFileExt),
+            "backup.tar.gz"
+        );
+        assert_eq!(
+            archives.apply_result_mode("backup.old.zip", ResultMode::OverwriteFileExt),
+            "backup.tar.gz"
+        );
+        assert_eq!(
+            archives.apply_result_mode("backup.tar.gz", ResultMode::OverwriteFileExt),
+            "backup.tar.gz"
+        );
+        assert_eq!(
+            archives.apply_result_mode("backup", ResultMode::AddIfNoFileExt),
+            "backup.tar.gz"
+        );
+        assert_eq!(
+            archives.apply_result_mode("backup.zip", ResultMode::AddIfNoFileExt),
+            "backup.zip"
+        );
+    }
+
+    #[test]
+    fn apply_result_mode_ignores_groups_without_plain_extension() {
+        for filters in [&["((.*\\.png))"][..], &[".*"], &[]] {
+            for mode in ResultMode::ALL {
+                assert_eq!(group(filters).apply_result_mode("photo", mode), "photo");
+            }
+        }
+    }
+}
@@ -1,10 +1,13 @@
+use std::collections::HashSet;
+use std::iter::FromIterator;
 use std::ops::{BitAnd, BitOr, BitXor, Not};
 
-/// File dialog behavior flags
-#[repr(transparent)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
-pub struct FileDialogFlags(pub sys::ImGuiFileDialogFlags_);
+reflectable! {
+    /// File dialog behavior flags
+    #[repr(transparent)]
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    pub struct FileDialogFlags(pub sys::ImGuiFileDialogFlags_);
+}
 
 impl FileDialogFlags {
     /// No special flags
@@ -78,6 +81,27 @@ impl FileDialogFlags {
         Self(bits)
     }
 
+    /// Get the names of all known flags that are set, in bit order.
+    ///
+    /// Composite constants (like `DEFAULT`) are listed as their individual flags.
+    /// Bits that don't correspond to a known flag are omitted.
+    ///
+    /// ```
+    /// use imgui_filedialog::FileDialogFlags;
+    ///
+    /// assert_eq!(
+    ///     FileDialogFlags::DEFAULT.names(),
+    ///     ["CONFIRM_OVERWRITE", "HIDE_COLUMN_TYPE", "MODAL"]
+    /// );
+    /// ```
+    pub fn names(&self) -> Vec<&'static str> {
+        FileDialogFlag::NAMED
+            .iter()
+            .filter(|(flag, _)| self.contains(flag.flags()))
+            .map(|(_, name)| *name)
+            .collect()
+    }
+
     /// Check if no flags are set
     #[inline]
     pub const fn is_empty(&self) -> bool {
@@ -159,6 +183,186 @@ impl FileDialogFlags {
     pub const fn complement(self) -> Self {
         Self(!self.0)
     }
+
+    /// Get `DEFAULT` with the `extra` flags added.
+    ///
+    /// Note that `FileDialogFlags::default()` is empty, `DEFAULT` is what
+    /// `FileDialogConfig::default()` uses.
+    ///
+    /// ```
+    /// use imgui_filedialog::FileDialogFlags;
+    ///
+    /// let flags = FileDialogFlags::with_defaults(FileDialogFlags::NATURAL_SORTING)
+    ///     .without(FileDialogFlags::MODAL);
+    /// assert!(flags.contains(FileDialogFlags::NATURAL_SORTING));
+    /// assert!(flags.contains(FileDialogFlags::CONFIRM_OVERWRITE | FileDialogFlags::HIDE_COLUMN_TYPE));
+    /// assert!(!flags.contains(FileDialogFlags::MODAL));
+    /// assert!(FileDialogFlags::DEFAULT.contains(FileDialogFlags::MODAL));
+    /// ```
+    #[inline]
+    pub const fn with_defaults(extra: Self) -> Self {
+        Self::DEFAULT.union(extra)
+    }
+
+    /// Get these flags with `flag` removed, e.g. to drop a single bit of `DEFAULT`
+    #[inline]
+    pub const fn without(self, flag: Self) -> Self {
+        self.difference(flag)
+    }
+
+    /// Get the individual flags that are set, e.g. to drive a panel of checkboxes.
+    ///
+    /// Bits that don't correspond to a known flag are dropped, so collecting the set back
+    /// into `FileDialogFlags` gives the same flags for anything built from the constants.
+    ///
+    /// ```
+    /// use imgui_filedialog::{FileDialogFlag, FileDialogFlags};
+    /// use std::collections::HashSet;
+    /// use std::iter::FromIterator;
+    ///
+    /// let set = FileDialogFlags::DEFAULT.to_set();
+    /// let expected: HashSet<_> = [
+    ///     FileDialogFlag::ConfirmOverwrite,
+    ///     FileDialogFlag::Modal,
+    ///     FileDialogFlag::HideColumnType,
+    /// ]
+    /// .iter()
+    /// .copied()
+    /// .collect();
+    /// assert_eq!(set, expected);
+    /// assert_eq!(FileDialogFlags::from_iter(set), FileDialogFlags::DEFAULT);
+    ///
+    /// // every flag is a distinct bit and survives the round trip
+    /// let all: FileDialogFlags = FileDialogFlag::ALL.iter().copied().collect();
+    /// assert_eq!(all.to_set().len(), FileDialogFlag::ALL.len());
+    /// assert_eq!(FileDialogFlags::from_iter(all.to_set()), all);
+    /// assert!(FileDialogFlags::empty().to_set().is_empty());
+    /// assert!(FileDialogFlags::from_iter(HashSet::new()).is_empty());
+    /// ```
+    pub fn to_set(&self) -> HashSet<FileDialogFlag> {
+        FileDialogFlag::ALL
+            .iter()
+            .copied()
+            .filter(|flag| self.contains(flag.flags()))
+            .collect()
+    }
+}
+
+impl FromIterator<FileDialogFlag> for FileDialogFlags {
+    fn from_iter<I: IntoIterator<Item = FileDialogFlag>>(iter: I) -> Self {
+        iter.into_iter()
+            .fold(Self::empty(), |flags, flag| flags | flag.flags())
+    }
+}
+
+impl From<FileDialogFlag> for FileDialogFlags {
+    #[inline]
+    fn from(flag: FileDialogFlag) -> Self {
+        flag.flags()
+    }
+}
+
+reflectable! {
+    /// A single file dialog flag, for working with flags as a set rather than bits.
+    ///
+    /// See `FileDialogFlags::to_set` and the `FromIterator` impl of `FileDialogFlags`.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    pub enum FileDialogFlag {
+        /// See `FileDialogFlags::CONFIRM_OVERWRITE`
+        ConfirmOverwrite,
+        /// See `FileDialogFlags::DONT_SHOW_HIDDEN_FILES`
+        DontShowHiddenFiles,
+        /// See `FileDialogFlags::DISABLE_CREATE_DIRECTORY_BUTTON`
+        DisableCreateDirectoryButton,
+        /// See `FileDialogFlags::HIDE_COLUMN_TYPE`
+        HideColumnType,
+        /// See `FileDialogFlags::HIDE_COLUMN_SIZE`
+        HideColumnSize,
+        /// See `FileDialogFlags::HIDE_COLUMN_DATE`
+        HideColumnDate,
+        /// See `FileDialogFlags::NO_DIALOG`
+        NoDialog,
+        /// See `FileDialogFlags::READONLY_FILENAME_FIELD`
+        ReadonlyFilenameField,
+        /// See `FileDialogFlags::CASE_INSENSITIVE_EXTENSION_FILTERING`
+        CaseInsensitiveExtensionFiltering,
+        /// See `FileDialogFlags::MODAL`
+        Modal,
+        /// See `FileDialogFlags::DISABLE_THUMBNAIL_MODE`
+        DisableThumbnailMode,
+        /// See `FileDialogFlags::DISABLE_PLACE_MODE`
+        DisablePlaceMode,
+        /// See `FileDialogFlags::DISABLE_QUICK_PATH_SELECTION`
+        DisableQuickPathSelection,
+        /// See `FileDialogFlags::SHOW_DEVICES_BUTTON`
+        ShowDevicesButton,
+        /// See `FileDialogFlags::NATURAL_SORTING`
+        NaturalSorting,
+    }
+}
+
+impl FileDialogFlag {
+    /// All flags with the names of their `FileDialogFlags` constants, in bit order
+    const NAMED: [(Self, &'static str); 15] = [
+        (Self::ConfirmOverwrite, "CONFIRM_OVERWRITE"),
+        (Self::DontShowHiddenFiles, "DONT_SHOW_HIDDEN_FILES"),
+        (
+            Self::DisableCreateDirectoryButton,
+            "DISABLE_CREATE_DIRECTORY_BUTTON",
+        ),
+        (Self::HideColumnType, "HIDE_COLUMN_TYPE"),
+        (Self::HideColumnSize, "HIDE_COLUMN_SIZE"),
+        (Self::HideColumnDate, "HIDE_COLUMN_DATE"),
+        (Self::NoDialog, "NO_DIALOG"),
+        (Self::ReadonlyFilenameField, "READONLY_FILENAME_FIELD"),
+        (
+            Self::CaseInsensitiveExtensionFiltering,
+            "CASE_INSENSITIVE_EXTENSION_FILTERING",
+        ),
+        (Self::Modal, "MODAL"),
+        (Self::DisableThumbnailMode, "DISABLE_THUMBNAIL_MODE"),
+        (Self::DisablePlaceMode, "DISABLE_PLACE_MODE"),
+        (
+            Self::DisableQuickPathSelection,
+            "DISABLE_QUICK_PATH_SELECTION",
+        ),
+        (Self::ShowDevicesButton, "SHOW_DEVICES_BUTTON"),
+        (Self::NaturalSorting, "NATURAL_SORTING"),
+    ];
+
+    /// All flags, in bit order
+    pub const ALL: [FileDialogFlag; 15] = {
+        let mut all = [Self::ConfirmOverwrite; 15];
+        let mut i = 0;
+        while i < all.len() {
+            all[i] = Self::NAMED[i].0;
+            i += 1;
+        }
+        all
+    };
+
+    /// Get the bit of this flag
+    pub const fn flags(self) -> FileDialogFlags {
+        match self {
+            Self::ConfirmOverwrite => FileDialogFlags::CONFIRM_OVERWRITE,
+            Self::DontShowHiddenFiles => FileDialogFlags::DONT_SHOW_HIDDEN_FILES,
+            Self::DisableCreateDirectoryButton => FileDialogFlags::DISABLE_CREATE_DIRECTORY_BUTTON,
+            Self::HideColumnType => FileDialogFlags::HIDE_COLUMN_TYPE,
+            Self::HideColumnSize => FileDialogFlags::HIDE_COLUMN_SIZE,
+            Self::HideColumnDate => FileDialogFlags::HIDE_COLUMN_DATE,
+            Self::NoDialog => FileDialogFlags::NO_DIALOG,
+            Self::ReadonlyFilenameField => FileDialogFlags::READONLY_FILENAME_FIELD,
+            Self::CaseInsensitiveExtensionFiltering => {
+                FileDialogFlags::CASE_INSENSITIVE_EXTENSION_FILTERING
+            }
+            Self::Modal => FileDialogFlags::MODAL,
+            Self::DisableThumbnailMode => FileDialogFlags::DISABLE_THUMBNAIL_MODE,
+            Self::DisablePlaceMode => FileDialogFlags::DISABLE_PLACE_MODE,
+            Self::DisableQuickPathSelection => FileDialogFlags::DISABLE_QUICK_PATH_SELECTION,
+            Self::ShowDevicesButton => FileDialogFlags::SHOW_DEVICES_BUTTON,
+            Self::NaturalSorting => FileDialogFlags::NATURAL_SORTING,
+        }
+    }
 }
 
 impl Default for FileDialogFlags {
@@ -239,11 +443,75 @@ impl From<FileDialogFlags> for sys::ImGuiFileDialogFlags_ {
     }
 }
 
-/// File style flags for customizing file appearance
-#[repr(transparent)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
-pub struct FileStyleFlags(pub sys::IGFD_FileStyleFlags_);
+reflectable! {
+    /// Optional column of the file list, see `FileDialog::set_column_visibility`
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    pub enum Column {
+        /// File type (extension) column
+        Type,
+        /// File size column
+        Size,
+        /// Modification date column
+        Date,
+    }
+}
+
+impl Column {
+    /// Get the flag hiding this column
+    pub const fn hide_flag(self) -> FileDialogFlags {
+        match self {
+            Self::Type => FileDialogFlags::HIDE_COLUMN_TYPE,
+            Self::Size => FileDialogFlags::HIDE_COLUMN_SIZE,
+            Self::Date => FileDialogFlags::HIDE_COLUMN_DATE,
+        }
+    }
+}
+
+reflectable! {
+    /// Column the file list can be sorted by, see `FileDialog::set_sort`
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    pub enum SortColumn {
+        /// File name column
+        Name,
+        /// File type (extension) column
+        Type,
+        /// File size column
+        Size,
+        /// Modification date column
+        Date,
+    }
+}
+
+impl SortColumn {
+    /// Get ImGuiFileDialog's sorting field of this column
+    pub(crate) const fn field(self) -> i32 {
+        match self {
+            Self::Name => 1,
+            Self::Type => 2,
+            Self::Size => 3,
+            Self::Date => 4,
+        }
+    }
+
+    /// Get the column of one of ImGuiFileDialog's sorting fields
+    pub(crate) const fn from_field(field: i32) -> Option<Self> {
+        match field {
+            1 => Some(Self::Name),
+            2 => Some(Self::Type),
+            3 => Some(Self::Size),
+            4 => Some(Self::Date),
+            _ => None,
+        }
+    }
+}
+
+reflectable! {
+    /// File style flags for customizing file appearance
+    #[repr(transparent)]
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct FileStyleFlags(pub sys::IGFD_FileStyleFlags_);
+}
 
 impl FileStyleFlags {
     /// No style
@@ -262,6 +530,16 @@ impl FileStyleFlags {
     /// Style when criteria is contained in full name
     pub const BY_CONTAINED_IN_FULL_NAME: Self = Self(sys::IGFD_FileStyleByContainedInFullName);
 
+    /// Individual flags with their names, in bit order
+    const NAMED: &'static [(Self, &'static str)] = &[
+        (Self::BY_TYPE_FILE, "BY_TYPE_FILE"),
+        (Self::BY_TYPE_DIR, "BY_TYPE_DIR"),
+        (Self::BY_TYPE_LINK, "BY_TYPE_LINK"),
+        (Self::BY_EXTENSION, "BY_EXTENSION"),
+        (Self::BY_FULL_NAME, "BY_FULL_NAME"),
+        (Self::BY_CONTAINED_IN_FULL_NAME, "BY_CONTAINED_IN_FULL_NAME"),
+    ];
+
     /// Create an empty set of flags
     #[inline]
     pub const fn empty() -> Self {
@@ -292,6 +570,17 @@ impl FileStyleFlags {
         Self(bits)
     }
 
+    /// Get the names of all known flags that are set, in bit order.
+    ///
+    /// Bits that don't correspond to a known flag are omitted.
+    pub fn names(&self) -> Vec<&'static str> {
+        Self::NAMED
+            .iter()
+            .filter(|(flag, _)| self.contains(*flag))
+            .map(|(_, name)| *name)
+            .collect()
+    }
+
     /// Check if no flags are set
     #[inline]
     pub const fn is_empty(&self) -> bool {
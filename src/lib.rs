@@ -1,57 +1,181 @@
 pub extern crate imgui_filedialog_sys as sys;
+#[macro_use]
+mod macros;
+mod backend;
+pub mod convert;
+pub mod error;
+pub mod filters;
 pub mod flags;
+pub mod manager;
+#[cfg(feature = "mock")]
+pub mod mock;
+pub mod paths;
 pub mod selection;
+#[cfg(test)]
+mod test_support;
 mod util;
 
-use std::ffi::CString;
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::collections::hash_map::DefaultHasher;
+use std::ffi::{CStr, CString};
+use std::hash::{Hash, Hasher};
+use std::os::raw::{c_char, c_void};
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::sync::mpsc;
 
-pub use crate::flags::{FileDialogFlags, FileStyleFlags};
+pub use crate::error::{DialogOutcomeError, FileDialogError, FilterParseError};
+pub use crate::filters::{FilterGroup, Filters};
+pub use crate::flags::{Column, FileDialogFlag, FileDialogFlags, FileStyleFlags, SortColumn};
+pub use crate::manager::FileDialogManager;
+pub use crate::paths::StandardDir;
+pub use crate::util::{has_extension, matches_extension, rgba8};
 pub use imgui::WindowFlags;
 
+use crate::convert::{from_imvec2, from_imvec4, to_imvec2};
 use crate::selection::Selection;
-use crate::util::ptr_into_string;
+use crate::util::{ffi_guard, ptr_clone_to_string, ptr_into_string};
 use imgui::ImString;
 
+// title of the overwrite confirmation popup, see OverWriteDialogTitleString in ImGuiFileDialog.cpp
+const OVERWRITE_DIALOG_TITLE: &str = "The selected file already exists!";
+
+// number of paths kept by FileDialog::navigation_history
+const NAVIGATION_HISTORY_LEN: usize = 32;
+
 // matches imgui-rs that also expects Into<MintVec2> as args
 type MintVec2 = mint::Vector2<f32>;
 type MintVec4 = mint::Vector4<f32>;
 
 /// Main file dialog context
+///
+/// A context can be shared between several dialogs via `FileDialog::with_context`.
+/// It holds the state of a single dialog, so only one of the dialogs sharing it can
+/// be open at a time.
 pub struct Context {
     ptr: *mut sys::ImGuiFileDialog,
+    // the C API can't enumerate file styles, so they are mirrored here
+    file_styles: RefCell<Vec<FileStyleRule>>,
+    // ImGuiFileDialog keeps calling the entry filter of the last opened dialog
+    active_entry_filter: RefCell<Option<EntryFilter>>,
+    // id of the open dialog, tracked by FileDialog's open and close
+    active_dialog: RefCell<Option<String>>,
 }
 
-#[must_use]
 impl Context {
     /// Create a new file dialog context
-    fn new() -> Self {
-        let igfd_ctx = unsafe { sys::IGFD_Create() };
-        Self { ptr: igfd_ctx }
+    #[must_use]
+    pub fn new(_ui: &mut imgui::Ui) -> Self {
+        // SAFETY: see FileDialog::with_context
+        let igfd_ctx = unsafe { backend::IGFD_Create() };
+        Self {
+            ptr: igfd_ctx,
+            file_styles: RefCell::new(Vec::new()),
+            active_entry_filter: RefCell::new(None),
+            active_dialog: RefCell::new(None),
+        }
+    }
+
+    /// Get the id of the dialog sharing this context that is currently open.
+    ///
+    /// The context holds a single dialog, so at most one id is reported: opening another
+    /// dialog while one is open is ignored by ImGuiFileDialog, and closing any of the
+    /// dialogs closes the open one. Useful to find a dialog that is never closed and
+    /// blocks the others. [`FileDialogManager::active_dialogs`] reports the same for
+    /// dialogs with a context each.
+    ///
+    /// ```
+    /// # let mut ctx = imgui::Context::create();
+    /// # ctx.io_mut().display_size = [800.0, 600.0];
+    /// # ctx.fonts().build_rgba32_texture();
+    /// # let ui = ctx.new_frame();
+    /// use imgui_filedialog::{Context, FileDialog};
+    /// use std::rc::Rc;
+    ///
+    /// let context = Rc::new(Context::new(ui));
+    /// let import = FileDialog::with_context(ui, context.clone(), "import");
+    /// let export = FileDialog::with_context(ui, context.clone(), "export");
+    ///
+    /// import.open("Import", None::<String>, Default::default());
+    /// export.open("Export", None::<String>, Default::default());
+    /// assert_eq!(context.active_dialog().as_deref(), Some("import"));
+    ///
+    /// import.close();
+    /// assert_eq!(context.active_dialog(), None);
+    /// ```
+    pub fn active_dialog(&self) -> Option<String> {
+        self.active_dialog.borrow().clone()
     }
 }
 
 impl Drop for Context {
     fn drop(&mut self) {
-        unsafe { sys::IGFD_Destroy(self.ptr) }
+        unsafe { backend::IGFD_Destroy(self.ptr) }
     }
 }
 
-/// File dialog configuration options
-#[derive(Debug, Clone)]
-#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
-pub struct FileDialogConfig {
-    /// Initial path to open
-    pub path: String,
-    /// Default filename
-    pub file_name: String,
-    /// Combined file path and name (takes precedence over separate path/filename)
-    pub file_path_name: String,
-    /// Maximum number of files that can be selected (0 = unlimited)
-    pub count_selection_max: i32,
-    /// Dialog behavior flags
-    pub flags: FileDialogFlags,
-    /// Width of the side pane (if enabled)
-    pub side_pane_width: f32,
+reflectable! {
+    /// File dialog configuration options
+    #[derive(Debug, Clone)]
+    pub struct FileDialogConfig {
+        /// Initial path to open
+        pub path: String,
+        /// Default filename
+        pub file_name: String,
+        /// Combined file path and name (takes precedence over separate path/filename)
+        pub file_path_name: String,
+        /// Maximum number of files that can be selected (0 = unlimited)
+        pub count_selection_max: i32,
+        /// Dialog behavior flags
+        pub flags: FileDialogFlags,
+        /// Width of the side pane (if enabled)
+        pub side_pane_width: f32,
+        /// Existing file to preselect when the dialog opens.
+        ///
+        /// ImGuiFileDialog has no highlight API, so the name is put into the file name field
+        /// (only if `file_name` and `file_path_name` are empty). In open mode, confirming
+        /// right away opens this file; in save mode it becomes the proposed name to save
+        /// over, subject to `CONFIRM_OVERWRITE`.
+        pub highlight: Option<String>,
+        /// Whether the user can resize the dialog window.
+        ///
+        /// `None` uses the window flags passed to `display` as they are. `Some(false)` adds
+        /// `NO_RESIZE`, `Some(true)` removes it. Neither layer applies `NO_RESIZE` on its own;
+        /// a window whose `display` max size is not larger than its min size can't be
+        /// resized because of ImGui's size constraints, so pass a larger max size as well.
+        pub resizable: Option<bool>,
+        /// Files to show as selected when the dialog opens, see `with_preselected`
+        pub preselected: Vec<PathBuf>,
+        /// Directory the results must stay within, for sandboxed apps.
+        ///
+        /// ImGuiFileDialog can't restrict navigation, so this is enforced on this crate's
+        /// side: `display` reopens the dialog at the root when the user navigated outside
+        /// of it. A confirmed result outside of it (e.g. a path typed into the file name
+        /// field) is not returned by `take_result`, which keeps the dialog open, and is
+        /// sent to the `result_sender` receivers as `DialogOutcome::Cancelled`. `is_ok`
+        /// still reports what the dialog reports. Paths are canonicalized where they
+        /// exist, resolving `..` and symlinks.
+        pub root_lock: Option<PathBuf>,
+        /// Create the missing parent directories of the result, e.g. to save into a new
+        /// project folder typed into the file name field.
+        ///
+        /// This touches the filesystem (`std::fs::create_dir_all`) and only runs when the
+        /// dialog is confirmed, for the paths returned by `take_result` and sent to the
+        /// `result_sender` receivers. Failures are reported by `try_take_result` and as
+        /// `DialogOutcome::Failed`.
+        pub create_parent_dirs: bool,
+        /// Extensions the result may have, with their leading dot like in filters (e.g.
+        /// `.png`), independent of the filter selected in the dialog.
+        ///
+        /// Typing a name into the file name field bypasses the filters; with this set, a
+        /// confirmed result with another extension is not returned by `take_result`, which
+        /// keeps the dialog open, is sent to the `result_sender` receivers as
+        /// `DialogOutcome::Cancelled`, and `accept` refuses it. `display` and `is_ok` still
+        /// report what the dialog reports. Extensions are compared like the filters
+        /// do, honoring `CASE_INSENSITIVE_EXTENSION_FILTERING`. `None` allows any name.
+        pub allowed_extensions: Option<Vec<String>>,
+    }
 }
 
 impl Default for FileDialogConfig {
@@ -63,38 +187,647 @@ impl Default for FileDialogConfig {
             count_selection_max: 1,
             flags: FileDialogFlags::DEFAULT,
             side_pane_width: 250.0,
+            highlight: None,
+            resizable: None,
+            preselected: Vec::new(),
+            root_lock: None,
+            create_parent_dirs: false,
+            allowed_extensions: None,
+        }
+    }
+}
+
+impl FileDialogConfig {
+    /// Create a config for a save dialog with the file name field prefilled.
+    ///
+    /// The file name is `stem` followed by the primary extension of the first filter
+    /// group, e.g. `untitled.png` for `"Images{.png,.jpg},Text{.txt}"`. If that group has
+    /// no plain extension the file name is just `stem`.
+    pub fn save_as(stem: &str, filters: &Filters) -> Self {
+        let ext = filters
+            .groups()
+            .first()
+            .and_then(FilterGroup::primary_extension)
+            .unwrap_or_default();
+        Self {
+            file_name: format!("{}{}", stem, ext),
+            ..Default::default()
+        }
+    }
+
+    /// Create a config suited to `mode`.
+    ///
+    /// ImGuiFileDialog can't hide the file name field, so open dialogs get
+    /// `READONLY_FILENAME_FIELD` on top of the default flags: the field then only shows
+    /// the selection. Save dialogs keep the default flags, including `CONFIRM_OVERWRITE`.
+    pub fn for_mode(mode: DialogMode) -> Self {
+        let flags = match mode {
+            DialogMode::Open => {
+                FileDialogFlags::with_defaults(FileDialogFlags::READONLY_FILENAME_FIELD)
+            }
+            DialogMode::Save => FileDialogFlags::DEFAULT,
+        };
+        Self {
+            flags,
+            ..Default::default()
+        }
+    }
+
+    /// Start the dialog in a standard user directory, e.g. `StandardDir::Documents`.
+    ///
+    /// Leaves `path` unchanged if the directory can't be determined, see [`paths`].
+    pub fn path_dir(&mut self, dir: StandardDir) -> &mut Self {
+        if let Some(path) = dir.path() {
+            self.path = path.to_string_lossy().into_owned();
+        }
+        self
+    }
+
+    /// Start the dialog next to an existing file, e.g. the document being edited.
+    ///
+    /// Sets `path` to the file's parent directory and `file_name` to its name. A path
+    /// without a parent (a root like `/`) is used as the directory, with an empty file
+    /// name; a bare file name keeps `path` empty, i.e. the working directory.
+    ///
+    /// ```
+    /// use imgui_filedialog::FileDialogConfig;
+    /// use std::path::Path;
+    ///
+    /// let mut config = FileDialogConfig::default();
+    /// config.near_file(Path::new("/home/user/notes.txt"));
+    /// assert_eq!(config.path, "/home/user");
+    /// assert_eq!(config.file_name, "notes.txt");
+    ///
+    /// config.near_file(Path::new("/"));
+    /// assert_eq!(config.path, "/");
+    /// assert_eq!(config.file_name, "");
+    /// ```
+    pub fn near_file(&mut self, path: &Path) -> &mut Self {
+        match path.parent() {
+            Some(parent) => {
+                self.path = parent.to_string_lossy().into_owned();
+                self.file_name = path
+                    .file_name()
+                    .map(|name| name.to_string_lossy().into_owned())
+                    .unwrap_or_default();
+            }
+            None => {
+                self.path = path.to_string_lossy().into_owned();
+                self.file_name = String::new();
+            }
+        }
+        self
+    }
+
+    /// Start the dialog where a previous dialog's result is, e.g. to pick an output file
+    /// next to the input picked before.
+    ///
+    /// Sets `path` to the directory of the outcome's first path, or to the path itself if
+    /// it's an existing directory (the result of a directory dialog). Cancelled and failed
+    /// outcomes leave the config unchanged. The outcome doesn't record the filter; filters
+    /// are passed to `open`, so reuse `FileDialog::filters` of the previous dialog for that.
+    ///
+    /// ```
+    /// use imgui_filedialog::{DialogOutcome, FileDialogConfig};
+    /// use std::path::PathBuf;
+    ///
+    /// let input = DialogOutcome::Ok(vec![PathBuf::from("/projects/scans/page1.png")]);
+    /// let mut config = FileDialogConfig::default();
+    /// config.continue_from(&input);
+    /// assert_eq!(config.path, "/projects/scans");
+    ///
+    /// let mut config = FileDialogConfig::default();
+    /// config.continue_from(&DialogOutcome::Cancelled);
+    /// assert_eq!(config.path, "");
+    /// ```
+    pub fn continue_from(&mut self, outcome: &DialogOutcome) -> &mut Self {
+        let Some(path) = (match outcome {
+            DialogOutcome::Ok(paths) => paths.first(),
+            DialogOutcome::Cancelled | DialogOutcome::Failed(_) => None,
+        }) else {
+            return self;
+        };
+        let dir = if path.is_dir() {
+            Some(path.as_path())
+        } else {
+            path.parent()
+        };
+        if let Some(dir) = dir.filter(|dir| !dir.as_os_str().is_empty()) {
+            self.path = dir.to_string_lossy().into_owned();
+        }
+        self
+    }
+
+    /// Set the side pane width from a base width and a DPI scale factor.
+    ///
+    /// `side_pane_width` is the only size-related field of the config, so it is
+    /// the only one affected: it becomes `base * scale`.
+    pub fn side_pane_width_scaled(&mut self, base: f32, scale: f32) {
+        self.side_pane_width = base * scale;
+    }
+
+    /// Resolve a relative `path` and `file_path_name` against `base` instead of the
+    /// working directory.
+    ///
+    /// An empty `path` (which the dialog treats as the working directory) becomes `base`;
+    /// an empty `file_path_name` stays empty, as it is only used when set. Absolute paths
+    /// are left as they are. Resolved paths are canonicalized; if that fails, e.g. because
+    /// the path doesn't exist, they are only joined onto `base`.
+    pub fn resolve_relative(&mut self, base: &Path) {
+        fn resolve(path: &str, base: &Path) -> String {
+            let path = Path::new(path);
+            if path.is_absolute() {
+                return path.to_string_lossy().into_owned();
+            }
+            let joined = base.join(path);
+            joined
+                .canonicalize()
+                .unwrap_or(joined)
+                .to_string_lossy()
+                .into_owned()
+        }
+
+        self.path = resolve(&self.path, base);
+        if !self.file_path_name.is_empty() {
+            self.file_path_name = resolve(&self.file_path_name, base);
+        }
+    }
+
+    /// Set files to show as selected when the dialog opens, e.g. to edit a previous choice.
+    ///
+    /// ImGuiFileDialog can't select files from code, so only the first file is applied:
+    /// its name is put into the file name field like `highlight` (which takes precedence),
+    /// and the dialog opens in its directory unless `path` is set. The full list is kept
+    /// in `preselected` for the app to compare against the new selection.
+    pub fn with_preselected(&mut self, files: &[PathBuf]) -> &mut Self {
+        self.preselected = files.to_vec();
+        self
+    }
+
+    /// Get a copy of this config with `flags` instead of its flags, leaving this one as is.
+    ///
+    /// Meant for a base config shared between opens, with flags tweaked per call:
+    ///
+    /// ```
+    /// use imgui_filedialog::{FileDialogConfig, FileDialogFlags};
+    ///
+    /// let base = FileDialogConfig {
+    ///     path: "/projects".to_string(),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let embedded = base.with_flags(FileDialogFlags::NO_DIALOG);
+    /// assert_eq!(embedded.flags, FileDialogFlags::NO_DIALOG);
+    /// assert_eq!(embedded.path, "/projects");
+    ///
+    /// let sorted = base.with_added_flags(FileDialogFlags::NATURAL_SORTING);
+    /// assert!(sorted.flags.contains(FileDialogFlags::DEFAULT | FileDialogFlags::NATURAL_SORTING));
+    ///
+    /// let modeless = base.with_removed_flags(FileDialogFlags::MODAL);
+    /// assert!(!modeless.flags.contains(FileDialogFlags::MODAL));
+    /// assert!(modeless.flags.contains(FileDialogFlags::CONFIRM_OVERWRITE));
+    ///
+    /// // the base config is unchanged
+    /// assert_eq!(base.flags, FileDialogFlags::DEFAULT);
+    /// assert_eq!(base.path, "/projects");
+    /// ```
+    pub fn with_flags(&self, flags: FileDialogFlags) -> FileDialogConfig {
+        FileDialogConfig {
+            flags,
+            ..self.clone()
+        }
+    }
+
+    /// Get a copy of this config with `flags` added to its flags, see `with_flags`
+    pub fn with_added_flags(&self, flags: FileDialogFlags) -> FileDialogConfig {
+        self.with_flags(self.flags | flags)
+    }
+
+    /// Get a copy of this config with `flags` removed from its flags, see `with_flags`
+    pub fn with_removed_flags(&self, flags: FileDialogFlags) -> FileDialogConfig {
+        self.with_flags(self.flags.without(flags))
+    }
+
+    /// Apply a reflected patch (e.g. edited in an inspector) to this config.
+    ///
+    /// The patch is applied to a copy first, so on error `self` is left unchanged.
+    /// Fails if the patch doesn't match the config's shape or if the resulting
+    /// `count_selection_max` is negative.
+    #[cfg(feature = "bevy_reflect")]
+    pub fn apply_reflect(
+        &mut self,
+        value: &dyn bevy_reflect::Reflect,
+    ) -> Result<(), FileDialogError> {
+        use bevy_reflect::PartialReflect;
+
+        let mut patched = self.clone();
+        patched
+            .try_apply(value.as_partial_reflect())
+            .map_err(FileDialogError::Reflect)?;
+        if patched.count_selection_max < 0 {
+            return Err(FileDialogError::InvalidSelectionMax(
+                patched.count_selection_max,
+            ));
+        }
+        *self = patched;
+        Ok(())
+    }
+}
+
+reflectable! {
+    /// How to handle file extensions in results
+    #[derive(Debug, Clone, Copy, Default)]
+    pub enum ResultMode {
+        /// Add file extension if none exists
+        #[default]
+        AddIfNoFileExt = sys::IGFD_ResultMode_AddIfNoFileExt as isize,
+        /// Overwrite existing file extension with current filter
+        OverwriteFileExt = sys::IGFD_ResultMode_OverwriteFileExt as isize,
+        /// Keep the input filename unchanged
+        KeepInputFile = sys::IGFD_ResultMode_KeepInputFile as isize,
+    }
+}
+
+impl ResultMode {
+    /// All result modes, e.g. for the items of a settings combo box
+    pub const ALL: [ResultMode; 3] = [
+        ResultMode::AddIfNoFileExt,
+        ResultMode::OverwriteFileExt,
+        ResultMode::KeepInputFile,
+    ];
+}
+
+impl std::fmt::Display for ResultMode {
+    /// Formats the mode as a label for settings UIs.
+    ///
+    /// ```
+    /// use imgui_filedialog::ResultMode;
+    ///
+    /// let labels: Vec<String> = ResultMode::ALL.iter().map(ToString::to_string).collect();
+    /// assert_eq!(
+    ///     labels,
+    ///     ["Add extension if missing", "Overwrite extension", "Keep input filename"]
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ResultMode::AddIfNoFileExt => "Add extension if missing",
+            ResultMode::OverwriteFileExt => "Overwrite extension",
+            ResultMode::KeepInputFile => "Keep input filename",
+        })
+    }
+}
+
+reflectable! {
+    /// Why the dialog was last closed
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum CloseReason {
+        /// The dialog was confirmed with the OK button
+        Ok,
+        /// The dialog was cancelled with the Cancel button, or closed in code without a result.
+        ///
+        /// There is no separate reason for clicking outside of a `MODAL` dialog: ImGui
+        /// doesn't close modal popups on outside clicks, so that never dismisses the dialog.
+        Cancelled,
+    }
+}
+
+reflectable! {
+    /// What the user is picking a file for, see `FileDialog::open_mode`
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+    pub enum DialogMode {
+        /// Picking existing files to open
+        #[default]
+        Open,
+        /// Picking a file to save to, which may not exist yet
+        Save,
+    }
+}
+
+reflectable! {
+    /// Locale category for `FileDialog::set_locales`, mapping to the C `LC_*` constants
+    ///
+    /// The numeric values of the constants differ between platforms (e.g. `LC_ALL` is 6
+    /// on Linux, 0 on macOS and Windows), the conversion to `i32` picks the right one.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum LocaleCategory {
+        /// All categories (`LC_ALL`)
+        All,
+        /// Number formatting, e.g. of file sizes (`LC_NUMERIC`)
+        Numeric,
+        /// Date and time formatting, e.g. of modification dates (`LC_TIME`)
+        Time,
+        /// String collation (`LC_COLLATE`)
+        Collate,
+    }
+}
+
+impl From<LocaleCategory> for i32 {
+    fn from(category: LocaleCategory) -> Self {
+        match category {
+            LocaleCategory::All => libc::LC_ALL,
+            LocaleCategory::Numeric => libc::LC_NUMERIC,
+            LocaleCategory::Time => libc::LC_TIME,
+            LocaleCategory::Collate => libc::LC_COLLATE,
+        }
+    }
+}
+
+/// Owned result of a dialog, as sent to the receivers of `FileDialog::result_sender`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DialogOutcome {
+    /// The dialog was confirmed, with the selected paths (the typed one if none was selected)
+    Ok(Vec<PathBuf>),
+    /// The dialog was cancelled
+    Cancelled,
+    /// The dialog was confirmed, but its result could not be prepared
+    Failed(DialogOutcomeError),
+}
+
+impl DialogOutcome {
+    /// Check whether the dialog picked another file than `baseline`, e.g. the file open
+    /// in an editor.
+    ///
+    /// Compares the first path of an OK outcome; cancelled and failed outcomes didn't pick
+    /// anything and report `false`. Both paths are canonicalized, so `..`, symlinks and (on
+    /// Windows) letter case don't make them differ. For paths that don't exist, the
+    /// nearest existing directory is canonicalized and the rest compared as written.
+    ///
+    /// ```
+    /// use imgui_filedialog::DialogOutcome;
+    ///
+    /// let dir = std::env::temp_dir().join("imgui-filedialog-differs-from");
+    /// std::fs::create_dir_all(&dir).unwrap();
+    /// let open = dir.join("open.txt");
+    /// std::fs::write(&open, "").unwrap();
+    ///
+    /// let detour = dir.join("..").join(dir.file_name().unwrap()).join("open.txt");
+    /// assert!(!DialogOutcome::Ok(vec![detour]).differs_from(&open));
+    /// assert!(DialogOutcome::Ok(vec![dir.join("new.txt")]).differs_from(&open));
+    /// assert!(!DialogOutcome::Cancelled.differs_from(&open));
+    /// ```
+    pub fn differs_from(&self, baseline: &Path) -> bool {
+        match self {
+            DialogOutcome::Ok(paths) => paths.first().is_some_and(|path| {
+                crate::util::resolve_path(path) != crate::util::resolve_path(baseline)
+            }),
+            DialogOutcome::Cancelled | DialogOutcome::Failed(_) => false,
+        }
+    }
+}
+
+/// Style registered for files matching a criteria
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileStyle {
+    /// Color used for matching files
+    pub color: MintVec4,
+    /// Icon text displayed in front of matching files
+    pub icon: Option<String>,
+}
+
+type EntryFilter = Rc<RefCell<Box<dyn FnMut(&Path) -> bool>>>;
+type ResultTransform = Box<dyn Fn(PathBuf) -> PathBuf>;
+
+/// Parameters the dialog was last opened with
+struct OpenParams {
+    title: String,
+    filters: Option<String>,
+    config: FileDialogConfig,
+}
+
+/// What `FileDialog::changed_this_frame` saw at its last call
+#[derive(Default)]
+struct ChangeSnapshot {
+    frame: Option<i32>,
+    changed: bool,
+    path: String,
+    selection: Vec<PathBuf>,
+}
+
+/// A file style registered through `FileDialog::set_file_style`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FileStyleRule {
+    /// What type of files the style applies to
+    pub flags: FileStyleFlags,
+    /// File extension or pattern to match
+    pub criteria: String,
+    /// Color used for matching files
+    pub color: MintVec4,
+    /// Icon text displayed in front of matching files
+    pub icon: Option<String>,
+    /// Index of the font used for matching files in the font atlas, in the order fonts
+    /// were added (`None` for the current font)
+    pub font: Option<usize>,
+}
+
+impl FileStyleRule {
+    /// Start building a style for files matching `criteria`.
+    ///
+    /// The color defaults to opaque white, the icon and font are unset.
+    ///
+    /// ```
+    /// use imgui_filedialog::{FileStyleFlags, FileStyleRule};
+    ///
+    /// let theme = vec![
+    ///     FileStyleRule::builder(FileStyleFlags::BY_EXTENSION, ".png")
+    ///         .color([0.4, 0.8, 0.4, 1.0])
+    ///         .icon("[img]")
+    ///         .build(),
+    ///     FileStyleRule::builder(FileStyleFlags::BY_TYPE_DIR, "")
+    ///         .color([0.9, 0.8, 0.3, 1.0])
+    ///         .font(1)
+    ///         .build(),
+    /// ];
+    /// assert_eq!(theme[0].icon.as_deref(), Some("[img]"));
+    /// assert_eq!(theme[1].font, Some(1));
+    /// ```
+    pub fn builder(flags: FileStyleFlags, criteria: impl Into<String>) -> FileStyleBuilder {
+        FileStyleBuilder {
+            rule: FileStyleRule {
+                flags,
+                criteria: criteria.into(),
+                color: MintVec4 {
+                    x: 1.0,
+                    y: 1.0,
+                    z: 1.0,
+                    w: 1.0,
+                },
+                icon: None,
+                font: None,
+            },
         }
     }
 }
 
-/// How to handle file extensions in results
-#[derive(Debug, Clone, Copy, Default)]
-#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
-pub enum ResultMode {
-    /// Add file extension if none exists
-    #[default]
-    AddIfNoFileExt = sys::IGFD_ResultMode_AddIfNoFileExt as isize,
-    /// Overwrite existing file extension with current filter
-    OverwriteFileExt = sys::IGFD_ResultMode_OverwriteFileExt as isize,
-    /// Keep the input filename unchanged
-    KeepInputFile = sys::IGFD_ResultMode_KeepInputFile as isize,
+/// A set of file styles that is applied as a whole, see `FileDialog::apply_theme`
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FileTheme {
+    /// Rules of the theme, in registration order
+    pub rules: Vec<FileStyleRule>,
+}
+
+/// Builder for a [`FileStyleRule`], see [`FileStyleRule::builder`]
+#[derive(Debug, Clone)]
+pub struct FileStyleBuilder {
+    rule: FileStyleRule,
+}
+
+impl FileStyleBuilder {
+    /// Set the color of matching files
+    pub fn color(mut self, color: impl Into<MintVec4>) -> Self {
+        self.rule.color = color.into();
+        self
+    }
+
+    /// Set the icon text displayed in front of matching files
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.rule.icon = Some(icon.into());
+        self
+    }
+
+    /// Set the index of the font used for matching files in the font atlas.
+    ///
+    /// Fonts are indexed in the order they were added, the default font being 0.
+    /// An index past the last font falls back to the current font.
+    pub fn font(mut self, index: usize) -> Self {
+        self.rule.font = Some(index);
+        self
+    }
+
+    /// Finish the style, ready to be passed to `FileDialog::set_file_styles`
+    pub fn build(self) -> FileStyleRule {
+        self.rule
+    }
+}
+
+/// Get the font at `index` of the current context's font atlas, or null if there is none
+fn atlas_font(index: usize) -> *mut sys::ImFont {
+    unsafe {
+        let fonts = &(*(*imgui::sys::igGetIO()).Fonts).Fonts;
+        if index < fonts.Size as usize {
+            *fonts.Data.add(index) as *mut sys::ImFont
+        } else {
+            std::ptr::null_mut()
+        }
+    }
 }
 
 /// Main file dialog interface
 pub struct FileDialog {
     id: ImString,
-    context: Context,
+    context: Rc<Context>,
+    close_reason: Cell<Option<CloseReason>>,
+    open_params: RefCell<Option<OpenParams>>,
+    last_display_frame: Cell<Option<i32>>,
+    result_senders: RefCell<Vec<mpsc::Sender<DialogOutcome>>>,
+    entry_filter: RefCell<Option<EntryFilter>>,
+    mode: Cell<DialogMode>,
+    current_path_cache: RefCell<Rc<str>>,
+    current_path_frame: Cell<Option<i32>>,
+    // result set by accept or cancel, reported instead of the dialog's until it's closed
+    pending_result: Cell<Option<bool>>,
+    remember_last_path: Cell<bool>,
+    last_path: RefCell<Option<String>>,
+    result_frame: Cell<Option<i32>>,
+    change_snapshot: RefCell<ChangeSnapshot>,
+    result_transform: RefCell<Option<ResultTransform>>,
+    // hash of what the listing depends on, and how often it changed
+    listing_key: Cell<Option<u64>>,
+    listing_revision: Cell<u64>,
+    // paths navigated away from, and the path seen at the last display
+    navigation_history: RefCell<Vec<String>>,
+    navigation_path: RefCell<Option<Rc<str>>>,
+    // message of the last failed try_* call
+    last_error: RefCell<Option<String>>,
+    // sort order set by set_sort, applied again after the next display
+    pending_sort: Cell<Option<(SortColumn, bool)>>,
 }
 
 impl FileDialog {
     /// Create a new file dialog with the given identifier
-    pub fn create(_ui: &mut imgui::Ui, id: &str) -> Self {
+    ///
+    /// ImGuiFileDialog uses the id as the dialog's key, so it must not be empty; an empty
+    /// id silently collides with every other empty-id dialog. Debug builds assert this,
+    /// use `try_create` to handle it as an error.
+    pub fn create(ui: &mut imgui::Ui, id: &str) -> Self {
+        let context = Rc::new(Context::new(ui));
+        Self::with_context(ui, context, id)
+    }
+
+    /// Create a new file dialog, failing if the identifier is empty
+    pub fn try_create(ui: &mut imgui::Ui, id: &str) -> Result<Self, FileDialogError> {
+        if id.is_empty() {
+            return Err(FileDialogError::EmptyId);
+        }
+        Ok(Self::create(ui, id))
+    }
+
+    /// Create a new file dialog with an id derived from the type `T` and `suffix`.
+    ///
+    /// The id is the type's name (as given by `std::any::type_name`) followed by
+    /// `::suffix`, so subsystems opening dialogs with the same suffix don't collide. This
+    /// only reduces the risk of collisions: the ids of all dialogs must still be unique,
+    /// and type names may change between compiler versions, so don't persist them.
+    ///
+    /// ```
+    /// # let mut ctx = imgui::Context::create();
+    /// # ctx.io_mut().display_size = [800.0, 600.0];
+    /// # ctx.fonts().build_rgba32_texture();
+    /// # let ui = ctx.new_frame();
+    /// use imgui_filedialog::FileDialog;
+    ///
+    /// struct Importer;
+    /// struct Exporter;
+    ///
+    /// let import = FileDialog::create_for::<Importer>(ui, "open");
+    /// let export = FileDialog::create_for::<Exporter>(ui, "open");
+    /// assert_ne!(import.id(), export.id());
+    /// assert!(import.id().ends_with("Importer::open"));
+    /// ```
+    pub fn create_for<T: Any>(ui: &mut imgui::Ui, suffix: &str) -> Self {
+        let id = format!("{}::{}", std::any::type_name::<T>(), suffix);
+        Self::create(ui, &id)
+    }
+
+    /// Get the identifier the dialog was created with
+    pub fn id(&self) -> &str {
+        self.id.to_str()
+    }
+
+    /// Create a new file dialog with the given identifier on a shared context
+    ///
+    /// The id must not be empty, see `create`.
+    pub fn with_context(_ui: &mut imgui::Ui, context: Rc<Context>, id: &str) -> Self {
+        debug_assert!(!id.is_empty(), "FileDialog id must not be empty");
+
         // SAFETY: mutable access to Ui means it's safe to make ImGui sys calls.
         // FileDialog is not Send + Sync because it holds a raw pointer (in Context),
         // therefore all other functions will be safe to call as we stay on this thread.
         Self {
-            context: Context::new(),
+            context,
             id: ImString::new(id),
+            close_reason: Cell::new(None),
+            open_params: RefCell::new(None),
+            last_display_frame: Cell::new(None),
+            result_senders: RefCell::new(Vec::new()),
+            entry_filter: RefCell::new(None),
+            mode: Cell::new(DialogMode::Open),
+            current_path_cache: RefCell::new(Rc::from("")),
+            current_path_frame: Cell::new(None),
+            pending_result: Cell::new(None),
+            remember_last_path: Cell::new(false),
+            last_path: RefCell::new(None),
+            result_frame: Cell::new(None),
+            change_snapshot: RefCell::new(ChangeSnapshot::default()),
+            result_transform: RefCell::new(None),
+            listing_key: Cell::new(None),
+            listing_revision: Cell::new(0),
+            navigation_history: RefCell::new(Vec::new()),
+            navigation_path: RefCell::new(None),
+            last_error: RefCell::new(None),
+            pending_sort: Cell::new(None),
         }
     }
 
@@ -110,11 +843,121 @@ impl FileDialog {
         filters: Option<impl Into<String>>,
         config: FileDialogConfig,
     ) {
-        let title_cstr = CString::new(title.into()).unwrap();
-        let filters_cstr = filters.map(|f| CString::new(f.into()).unwrap());
+        self.mode.set(DialogMode::Open);
+        self.open_impl(
+            title.into(),
+            filters.map(Into::into),
+            self.with_last_path(config),
+        );
+    }
+
+    /// Like `open`, but records whether the dialog opens or saves a file.
+    ///
+    /// The mode doesn't change how ImGuiFileDialog behaves, use the config for that (e.g.
+    /// `CONFIRM_OVERWRITE` for save dialogs). It is reported by `mode` so code handling
+    /// the result can branch on it.
+    pub fn open_mode(
+        &self,
+        mode: DialogMode,
+        title: impl Into<String>,
+        filters: Option<impl Into<String>>,
+        config: FileDialogConfig,
+    ) {
+        self.mode.set(mode);
+        self.open_impl(
+            title.into(),
+            filters.map(Into::into),
+            self.with_last_path(config),
+        );
+    }
 
-        let path_cstr = CString::new(config.path.as_str()).unwrap();
-        let filename_cstr = CString::new(config.file_name.as_str()).unwrap();
+    /// Get the mode the dialog was last opened with.
+    ///
+    /// Dialogs opened with `open` or `try_open`, or never opened, report `DialogMode::Open`.
+    pub fn mode(&self) -> DialogMode {
+        self.mode.get()
+    }
+
+    /// Like `open`, but validates the filter string first.
+    ///
+    /// Where `open` passes malformed filters on and lets the dialog skip what it can't
+    /// parse, this fails with a precise error instead. A validated [`Filters`] can also be
+    /// passed to `open` via its `Display` implementation.
+    pub fn try_open(
+        &self,
+        title: impl Into<String>,
+        filters: Option<&str>,
+        config: FileDialogConfig,
+    ) -> Result<(), FileDialogError> {
+        if let Some(filters) = filters {
+            self.record_error(
+                Filters::from_str_checked(filters).map_err(FileDialogError::InvalidFilters),
+            )?;
+        }
+        self.mode.set(DialogMode::Open);
+        self.open_impl(
+            title.into(),
+            filters.map(str::to_string),
+            self.with_last_path(config),
+        );
+        Ok(())
+    }
+
+    /// Make the dialog reopen in the directory it was last closed in.
+    ///
+    /// When enabled, `close` remembers the current path and the next `open`, `open_mode` or
+    /// `try_open` of this dialog starts there, replacing the config's `path`. ImGuiFileDialog
+    /// forgets the path with each open, so it's stored on the `FileDialog`, separately for
+    /// each dialog even if they share a `Context`. A `file_path_name` in the config still
+    /// takes precedence. Disabling forgets the remembered path.
+    pub fn remember_last_path(&self, enabled: bool) {
+        self.remember_last_path.set(enabled);
+        if !enabled {
+            self.last_path.replace(None);
+        }
+    }
+
+    /// Replace the config's path with the remembered one, see `remember_last_path`
+    fn with_last_path(&self, config: FileDialogConfig) -> FileDialogConfig {
+        match self.last_path.borrow().clone() {
+            Some(path) => FileDialogConfig { path, ..config },
+            None => config,
+        }
+    }
+
+    fn open_impl(&self, title: String, filters: Option<String>, config: FileDialogConfig) {
+        self.close_reason.set(None);
+        self.current_path_frame.set(None);
+        self.pending_result.set(None);
+        // opening scans the directory again
+        self.invalidate_listing();
+
+        let title_cstr = CString::new(title.as_str()).unwrap();
+        let filters_cstr = filters.as_deref().map(|f| CString::new(f).unwrap());
+
+        // only the first preselected file can be shown, see with_preselected
+        let preselected = config
+            .preselected
+            .first()
+            .filter(|_| config.highlight.is_none());
+        let path = match preselected.and_then(|file| file.parent()) {
+            Some(parent) if config.path.is_empty() => parent.to_string_lossy().into_owned(),
+            _ => config.path.clone(),
+        };
+        let highlight = config.highlight.clone().or_else(|| {
+            preselected
+                .and_then(|file| file.file_name())
+                .map(|name| name.to_string_lossy().into_owned())
+        });
+
+        let path_cstr = CString::new(path).unwrap();
+        let file_name = match &highlight {
+            Some(highlight) if config.file_name.is_empty() && config.file_path_name.is_empty() => {
+                highlight.as_str()
+            }
+            _ => config.file_name.as_str(),
+        };
+        let filename_cstr = CString::new(file_name).unwrap();
         let filepath_cstr = CString::new(config.file_path_name.as_str()).unwrap();
 
         let c_config = sys::IGFD_FileDialog_Config {
@@ -128,133 +971,1378 @@ impl FileDialog {
             flags: config.flags.bits() as sys::ImGuiFileDialogFlags,
         };
 
-        unsafe {
-            sys::IGFD_OpenDialog(
-                self.context.ptr,
-                self.id.as_ptr(),
-                title_cstr.as_ptr(),
-                filters_cstr
-                    .as_ref() // important - otherwise value gets moved out and dropped
-                    .map_or(std::ptr::null(), |c| c.as_ptr()),
-                c_config,
-            );
+        let filters_ptr = filters_cstr
+            .as_ref() // important - otherwise value gets moved out and dropped
+            .map_or(std::ptr::null(), |c| c.as_ptr());
+        let entry_filter = self.entry_filter.borrow().clone();
+        // ImGuiFileDialog ignores opening while a dialog is showing, and keeps calling the
+        // entry filter that dialog was opened with
+        let ignored = unsafe { backend::IGFD_IsOpened(self.context.ptr) };
+        unsafe {
+            match &entry_filter {
+                Some(entry_filter) => backend::IGFD_Ext_OpenDialogWithEntryFilter(
+                    self.context.ptr,
+                    self.id.as_ptr(),
+                    title_cstr.as_ptr(),
+                    filters_ptr,
+                    c_config,
+                    Some(entry_filter_trampoline),
+                    Rc::as_ptr(entry_filter) as *mut c_void,
+                ),
+                None => backend::IGFD_OpenDialog(
+                    self.context.ptr,
+                    self.id.as_ptr(),
+                    title_cstr.as_ptr(),
+                    filters_ptr,
+                    c_config,
+                ),
+            }
+        }
+        // the context keeps the filter alive for as long as ImGuiFileDialog may call it
+        if !ignored {
+            self.context.active_entry_filter.replace(entry_filter);
+        }
+        if self.is_key_opened() {
+            self.context
+                .active_dialog
+                .replace(Some(self.id().to_string()));
+        }
+
+        self.open_params.replace(Some(OpenParams {
+            title,
+            filters,
+            config,
+        }));
+    }
+
+    /// Get the filters the dialog was last opened with
+    pub fn filters(&self) -> Option<String> {
+        self.open_params
+            .borrow()
+            .as_ref()
+            .and_then(|params| params.filters.clone())
+    }
+
+    /// Swap the filters of the dialog.
+    ///
+    /// ImGuiFileDialog only accepts filters when opening, so if the dialog is currently
+    /// open it gets reopened with the new filters. The current directory and the file name
+    /// typed so far survive the swap; the selection and scroll position are reset.
+    /// If the dialog isn't currently open, only the filters reported by `filters` are
+    /// updated. Does nothing if the dialog was never opened.
+    pub fn set_filters(&self, filters: Option<impl Into<String>>) {
+        let filters = filters.map(Into::into);
+        self.update_open_params(|params| params.filters = filters);
+    }
+
+    /// Show or hide a column of the file list.
+    ///
+    /// Works like `set_filters`: the `HIDE_COLUMN_*` flags are only read when opening, so
+    /// an open dialog is reopened, keeping the current directory and the typed file name
+    /// but resetting the selection and scroll position. Nothing happens if the column
+    /// already has the requested visibility or the dialog was never opened.
+    pub fn set_column_visibility(&self, column: Column, visible: bool) {
+        let flag = column.hide_flag();
+        let changed = self
+            .open_params
+            .borrow()
+            .as_ref()
+            .is_some_and(|params| params.config.flags.contains(flag) == visible);
+        if changed {
+            self.update_open_params(|params| params.config.flags.set(flag, !visible));
+        }
+    }
+
+    /// Hide files for which `filter` returns `false`, on top of the extension filters.
+    ///
+    /// `filter` gets the full path of each file in the listed directory, e.g. to hide files
+    /// over a certain size. Directories are always shown so the user can still navigate.
+    /// ImGuiFileDialog only takes the filter when opening, so an open dialog is reopened
+    /// like in `set_filters`. A panic in `filter` is caught and shows the file:
+    ///
+    /// ```
+    /// # let mut ctx = imgui::Context::create();
+    /// # ctx.io_mut().display_size = [800.0, 600.0];
+    /// # ctx.fonts().build_rgba32_texture();
+    /// # ctx.set_ini_filename(None);
+    /// # let dialog = imgui_filedialog::FileDialog::create(ctx.new_frame(), "doc");
+    /// # ctx.render();
+    /// use imgui_filedialog::FileDialogConfig;
+    ///
+    /// let dir = std::env::temp_dir().join("imgui-filedialog-entry-filter");
+    /// std::fs::create_dir_all(&dir).unwrap();
+    /// std::fs::write(dir.join("file.txt"), "").unwrap();
+    ///
+    /// // panics while ImGuiFileDialog scans the directory, without aborting the process
+    /// dialog.set_entry_filter(|_| panic!("broken filter"));
+    /// dialog.open("Open", Some(".txt"), FileDialogConfig {
+    ///     path: dir.display().to_string(),
+    ///     ..Default::default()
+    /// });
+    /// # let _ui = ctx.new_frame();
+    /// dialog.display_simple();
+    /// # ctx.render();
+    /// ```
+    pub fn set_entry_filter(&self, filter: impl FnMut(&Path) -> bool + 'static) {
+        self.entry_filter
+            .replace(Some(Rc::new(RefCell::new(Box::new(filter)))));
+        self.update_open_params(|_| {});
+    }
+
+    /// Remove the filter set with `set_entry_filter`, reopening the dialog if it's open
+    pub fn clear_entry_filter(&self) {
+        if self.entry_filter.take().is_some() {
+            self.update_open_params(|_| {});
+        }
+    }
+
+    /// Change the parameters the dialog was opened with, reopening it if it's open
+    fn update_open_params(&self, update: impl FnOnce(&mut OpenParams)) {
+        let Some(mut params) = self.open_params.borrow_mut().take() else {
+            return;
+        };
+        update(&mut params);
+
+        if self.is_key_opened() {
+            let config = FileDialogConfig {
+                path: self.current_path(),
+                file_name: self.current_filename(ResultMode::KeepInputFile),
+                file_path_name: String::new(),
+                ..params.config
+            };
+            // ImGuiFileDialog ignores opening a dialog that is showing, close it first
+            unsafe { backend::IGFD_CloseDialog(self.context.ptr) };
+            self.open_impl(params.title, params.filters, config);
+        } else {
+            self.open_params.replace(Some(params));
+        }
+    }
+
+    /// Displays the dialog and returns true if a result was obtained (ok or not).
+    ///
+    /// Arguments:
+    /// - `flags` - ImGui window flags
+    /// - `min_size` - Minimum window size
+    /// - `max_size` - Maximum window size
+    pub fn display(
+        &self,
+        flags: WindowFlags,
+        min_size: impl Into<MintVec2>,
+        max_size: impl Into<MintVec2>,
+    ) -> bool {
+        // displaying the same dialog twice in a frame confuses ImGuiFileDialog's state
+        // (flicker, stuck dialogs), so catch it early in debug builds
+        if cfg!(debug_assertions) {
+            let frame = unsafe { imgui::sys::igGetFrameCount() };
+            let last_frame = self.last_display_frame.replace(Some(frame));
+            debug_assert!(
+                last_frame != Some(frame),
+                "FileDialog {:?} displayed twice in frame {}",
+                self.id.to_str(),
+                frame
+            );
+        }
+
+        self.enforce_root_lock();
+
+        let mut flags = flags;
+        if let Some(resizable) = self
+            .open_params
+            .borrow()
+            .as_ref()
+            .and_then(|params| params.config.resizable)
+        {
+            flags.set(WindowFlags::NO_RESIZE, !resizable);
+        }
+
+        let displayed = unsafe {
+            backend::IGFD_DisplayDialog(
+                self.context.ptr,
+                self.id.as_ptr(),
+                flags.bits() as sys::ImGuiWindowFlags,
+                to_imvec2(min_size),
+                to_imvec2(max_size),
+            )
+        };
+        if self.is_key_opened() {
+            // queried once after the dialog had its chance to navigate, and shared by the
+            // trackers and `current_path_cached`
+            let path = self.refresh_current_path();
+            self.track_navigation(&path);
+            self.apply_pending_sort();
+            self.update_listing_revision(&path);
+        }
+        let has_result = displayed || self.pending_result.get().is_some();
+        if has_result {
+            self.result_frame
+                .set(Some(unsafe { imgui::sys::igGetFrameCount() }));
+        }
+        has_result
+    }
+
+    /// Like `display`, but fails if the dialog can't be displayed meaningfully.
+    ///
+    /// Calling `display` every frame before the dialog is opened is fine and simply
+    /// returns `false`; use this variant where displaying a never-opened dialog
+    /// indicates a lifecycle bug you want surfaced. It also fails instead of calling into
+    /// ImGuiFileDialog if its context couldn't be created or no ImGui context is current,
+    /// e.g. after the `imgui::Context` was dropped.
+    pub fn try_display(
+        &self,
+        flags: WindowFlags,
+        min_size: impl Into<MintVec2>,
+        max_size: impl Into<MintVec2>,
+    ) -> Result<bool, FileDialogError> {
+        let checked = if self.context.ptr.is_null() {
+            Err(FileDialogError::NullContext)
+        } else if unsafe { imgui::sys::igGetCurrentContext() }.is_null() {
+            Err(FileDialogError::NoImGuiContext)
+        } else if self.open_params.borrow().is_none() {
+            Err(FileDialogError::NeverOpened)
+        } else {
+            Ok(())
+        };
+        self.record_error(checked)?;
+        Ok(self.display(flags, min_size, max_size))
+    }
+
+    /// Like `display`, but places the dialog window at `pos` when it appears.
+    ///
+    /// `pivot` selects the point of the window placed at `pos`, e.g. `[0.5, 0.5]` centers
+    /// the window on `pos` and `[0.0, 0.0]` places its top-left corner there.
+    /// The position is only applied when the window appears, so the user can still move
+    /// it afterwards unless `flags` contains `NO_MOVE`. It has no effect with `NO_DIALOG`,
+    /// where the dialog is drawn into the host window.
+    pub fn display_at(
+        &self,
+        pos: impl Into<MintVec2>,
+        pivot: impl Into<MintVec2>,
+        flags: WindowFlags,
+        min_size: impl Into<MintVec2>,
+        max_size: impl Into<MintVec2>,
+    ) -> bool {
+        // only set the position if the dialog's window is submitted, it would apply to the
+        // next window of the application otherwise
+        if !self.is_embedded() && self.is_key_opened() {
+            let pos = pos.into();
+            let pivot = pivot.into();
+            unsafe {
+                imgui::sys::igSetNextWindowPos(
+                    imgui::sys::ImVec2 { x: pos.x, y: pos.y },
+                    imgui::sys::ImGuiCond_Appearing as imgui::sys::ImGuiCond,
+                    imgui::sys::ImVec2 {
+                        x: pivot.x,
+                        y: pivot.y,
+                    },
+                );
+            }
+        }
+        self.display(flags, min_size, max_size)
+    }
+
+    /// Like `display`, but places the dialog window in the viewport `viewport_id`.
+    ///
+    /// With multi-viewport enabled (`ConfigFlags::VIEWPORTS_ENABLE` on the imgui context),
+    /// windows appear in whichever OS window ImGui picks, which in docking setups is not
+    /// necessarily the one the user is working in. The viewport is set every frame, so the
+    /// dialog stays in that OS window. Without multi-viewport this behaves like `display`.
+    /// Has no effect with `NO_DIALOG`, where the dialog is drawn into the host window.
+    ///
+    /// `viewport_id` is the `ID` of an `ImGuiViewport`, e.g.
+    /// `(*imgui::sys::igGetWindowViewport()).ID` for the viewport of the current window.
+    /// imgui-rs has no public conversion from its `Viewport::id`.
+    #[cfg(feature = "docking")]
+    pub fn display_in_viewport(
+        &self,
+        viewport_id: imgui::sys::ImGuiID,
+        flags: WindowFlags,
+        min_size: impl Into<MintVec2>,
+        max_size: impl Into<MintVec2>,
+    ) -> bool {
+        // only set the viewport if the dialog's window is submitted, see display_at
+        if !self.is_embedded() && self.is_key_opened() {
+            unsafe { imgui::sys::igSetNextWindowViewport(viewport_id) };
+        }
+        self.display(flags, min_size, max_size)
+    }
+
+    /// Like `display`, but inside a child region of the current window, e.g. a fixed
+    /// part of a tool panel.
+    ///
+    /// The dialog must be opened with `NO_DIALOG`, so it draws into the child instead of
+    /// opening a window of its own, and without `MODAL`, which ImGuiFileDialog would try
+    /// to end a popup for; debug builds assert both. The child is begun and ended
+    /// here, even while the dialog is closed, which keeps the panel layout stable. It
+    /// doesn't scroll itself: the dialog fills it and scrolls its file list on its own.
+    /// A zero `size` component fills the available space, like for any child window.
+    /// Returns `false` while the child is clipped, as the dialog isn't drawn then.
+    ///
+    /// ```
+    /// # let mut ctx = imgui::Context::create();
+    /// # ctx.io_mut().display_size = [800.0, 600.0];
+    /// # ctx.fonts().build_rgba32_texture();
+    /// # ctx.set_ini_filename(None);
+    /// # let dialog = imgui_filedialog::FileDialog::create(ctx.new_frame(), "doc");
+    /// # ctx.render();
+    /// use imgui_filedialog::{FileDialogConfig, FileDialogFlags, ResultMode};
+    ///
+    /// dialog.open("Assets", Some(".png"), FileDialogConfig {
+    ///     flags: FileDialogFlags::NO_DIALOG,
+    ///     ..Default::default()
+    /// });
+    ///
+    /// let ui = ctx.new_frame();
+    /// ui.window("Tools").build(|| {
+    ///     ui.text("Pick an asset:");
+    ///     // full width, 250 pixels high
+    ///     if dialog.display_in_child(ui, "asset_picker", [0.0, 250.0]) {
+    ///         if dialog.is_ok() {
+    ///             println!("{:?}", dialog.current_file_path_name(ResultMode::default()));
+    ///         }
+    ///         dialog.close();
+    ///     }
+    /// });
+    /// # ctx.render();
+    /// ```
+    pub fn display_in_child(
+        &self,
+        ui: &imgui::Ui,
+        id: impl AsRef<str>,
+        size: impl Into<MintVec2>,
+    ) -> bool {
+        debug_assert!(
+            self.open_params.borrow().as_ref().is_none_or(|params| {
+                params.config.flags.contains(FileDialogFlags::NO_DIALOG)
+                    && !params.config.flags.contains(FileDialogFlags::MODAL)
+            }),
+            "FileDialog {:?} must be opened with NO_DIALOG and without MODAL to be displayed in a child",
+            self.id.to_str()
+        );
+        ui.child_window(id)
+            .size(size)
+            .scrollable(false)
+            .scroll_bar(false)
+            .build(|| {
+                self.display(
+                    WindowFlags::empty(),
+                    MintVec2 { x: 0.0, y: 0.0 },
+                    MintVec2 {
+                        x: f32::MAX,
+                        y: f32::MAX,
+                    },
+                )
+            })
+            .unwrap_or(false)
+    }
+
+    /// Display the dialog with default parameters
+    pub fn display_simple(&self) -> bool {
+        self.display(
+            WindowFlags::NO_COLLAPSE,
+            MintVec2 { x: 200.0, y: 300.0 },
+            MintVec2 { x: 700.0, y: 500.0 },
+        )
+    }
+
+    /// Like `display`, but lets ImGui size the dialog to its content.
+    ///
+    /// Adds `WindowFlags::ALWAYS_AUTO_RESIZE` and no maximum size, which suits small
+    /// pickers, e.g. embedded in a tool panel with `NO_DIALOG`. ImGuiFileDialog lays out
+    /// its content in a child filling the window, which has no size of its own, so the
+    /// minimum size of `display_simple` is kept to stop the window from collapsing.
+    /// An auto-resized window can't be resized by the user, so the config's `resizable`
+    /// setting has no visible effect here; use `display` for a resizable dialog.
+    pub fn display_autosize(&self, flags: WindowFlags) -> bool {
+        self.display(
+            flags | WindowFlags::ALWAYS_AUTO_RESIZE,
+            MintVec2 { x: 200.0, y: 300.0 },
+            MintVec2 {
+                x: f32::MAX,
+                y: f32::MAX,
+            },
+        )
+    }
+
+    /// Displays the dialog and, once it got a result, closes it and returns the chosen path.
+    ///
+    /// Shorthand for `display` followed by `is_ok`, `current_file_path_name` with the default
+    /// `ResultMode`, and `close`. Returns `None` while the dialog is showing and when it was
+    /// cancelled; a cancelled dialog is closed as well. A result outside the config's
+    /// `root_lock` or `allowed_extensions` isn't returned either, the dialog then stays
+    /// open.
+    ///
+    /// If the parent directory of the path can't be created (see
+    /// `FileDialogConfig::create_parent_dirs`), the path is returned anyway; use
+    /// `try_take_result` to handle that.
+    pub fn take_result(
+        &self,
+        flags: WindowFlags,
+        min_size: impl Into<MintVec2>,
+        max_size: impl Into<MintVec2>,
+    ) -> Option<PathBuf> {
+        match self.try_take_result(flags, min_size, max_size) {
+            Ok(path) => path,
+            Err(DialogOutcomeError::CreateDir { path, .. }) => Some(path),
+        }
+    }
+
+    /// Like `take_result`, but fails if the result can't be prepared.
+    ///
+    /// The dialog is closed either way.
+    pub fn try_take_result(
+        &self,
+        flags: WindowFlags,
+        min_size: impl Into<MintVec2>,
+        max_size: impl Into<MintVec2>,
+    ) -> Result<Option<PathBuf>, DialogOutcomeError> {
+        if !self.display(flags, min_size, max_size) {
+            return Ok(None);
+        }
+        if self.is_ok() && !self.result_accepted() {
+            // keep the dialog open instead of returning a result outside the config's limits
+            self.pending_result.set(None);
+            return Ok(None);
+        }
+        let path = self.is_ok().then(|| {
+            self.prepare_result(PathBuf::from(
+                self.current_file_path_name(ResultMode::default()),
+            ))
+        });
+        self.close();
+        self.record_error(path.transpose())
+    }
+
+    /// Rewrite result paths before they are returned, e.g. to resolve symlinks.
+    ///
+    /// The transform is applied to the paths returned by `take_result` and sent to the
+    /// `result_sender` receivers. Accessors reading the dialog's state directly, like
+    /// `current_file_path_name` and `selection`, return the paths as the dialog has them.
+    ///
+    /// ```
+    /// # let mut ctx = imgui::Context::create();
+    /// # ctx.io_mut().display_size = [800.0, 600.0];
+    /// # ctx.fonts().build_rgba32_texture();
+    /// # ctx.set_ini_filename(None);
+    /// # let dialog = imgui_filedialog::FileDialog::create(ctx.new_frame(), "doc");
+    /// # ctx.render();
+    /// use imgui::WindowFlags;
+    /// use imgui_filedialog::FileDialogConfig;
+    ///
+    /// let dir = std::env::temp_dir();
+    /// std::fs::write(dir.join("transformed.txt"), "").unwrap();
+    /// // a path with a detour the transform resolves
+    /// let path = dir.join("..").join(dir.file_name().unwrap());
+    ///
+    /// dialog.set_result_transform(|path| path.canonicalize().unwrap_or(path));
+    /// dialog.open("Open", Some(".txt"), FileDialogConfig {
+    ///     path: path.display().to_string(),
+    ///     file_name: "transformed.txt".to_string(),
+    ///     ..Default::default()
+    /// });
+    /// # #[cfg(feature = "mock")]
+    /// # {
+    /// #     imgui_filedialog::mock::set_current_path(&path.display().to_string());
+    /// #     imgui_filedialog::mock::set_file_name("transformed.txt");
+    /// # }
+    /// # let _ui = ctx.new_frame();
+    /// dialog.display_simple();
+    /// assert!(dialog.accept());
+    /// # ctx.render();
+    /// # let _ui = ctx.new_frame();
+    /// let path = dialog.take_result(WindowFlags::empty(), [200.0, 300.0], [700.0, 500.0]);
+    /// assert_eq!(path, Some(dir.join("transformed.txt").canonicalize().unwrap()));
+    /// ```
+    pub fn set_result_transform(&self, transform: impl Fn(PathBuf) -> PathBuf + 'static) {
+        self.result_transform.replace(Some(Box::new(transform)));
+    }
+
+    /// Remove the transform set with `set_result_transform`
+    pub fn clear_result_transform(&self) {
+        self.result_transform.replace(None);
+    }
+
+    /// Apply the transform set with `set_result_transform` and create the parent
+    /// directory if the config asks for it
+    fn prepare_result(&self, path: PathBuf) -> Result<PathBuf, DialogOutcomeError> {
+        let path = match &*self.result_transform.borrow() {
+            Some(transform) => transform(path),
+            None => path,
+        };
+        let create_parent_dirs = self
+            .open_params
+            .borrow()
+            .as_ref()
+            .is_some_and(|params| params.config.create_parent_dirs);
+        if let Some(parent) = path.parent().filter(|_| create_parent_dirs) {
+            if let Err(err) = std::fs::create_dir_all(parent) {
+                return Err(DialogOutcomeError::CreateDir {
+                    path,
+                    kind: err.kind(),
+                });
+            }
+        }
+        Ok(path)
+    }
+
+    /// Confirm the dialog from code, as if the user clicked OK.
+    ///
+    /// The next `display` returns `true` and `is_ok` reports `true` until the dialog is
+    /// closed, so the result is read the usual way (`current_file_path_name`, `selection`).
+    /// ImGuiFileDialog resets its own result flags every frame, so this is tracked by the
+    /// wrapper: the `CONFIRM_OVERWRITE` prompt is skipped. Like the OK button, it only works
+    /// while a file name is entered, and with one of the config's `allowed_extensions`;
+    /// returns whether the dialog was accepted.
+    ///
+    /// ```
+    /// # let mut ctx = imgui::Context::create();
+    /// # ctx.io_mut().display_size = [800.0, 600.0];
+    /// # ctx.fonts().build_rgba32_texture();
+    /// # ctx.set_ini_filename(None);
+    /// # let dialog = imgui_filedialog::FileDialog::create(ctx.new_frame(), "doc");
+    /// # ctx.render();
+    /// use imgui_filedialog::FileDialogConfig;
+    ///
+    /// for (typed, allowed) in [("notes.txt", true), ("NOTES.TXT", false), ("notes.exe", false)] {
+    ///     dialog.open("Save", Some(".txt"), FileDialogConfig {
+    ///         file_name: typed.to_string(),
+    ///         allowed_extensions: Some(vec![".txt".to_string(), ".md".to_string()]),
+    ///         ..Default::default()
+    ///     });
+    /// #   #[cfg(feature = "mock")]
+    /// #   imgui_filedialog::mock::set_file_name(typed);
+    /// #   let _ui = ctx.new_frame();
+    ///     dialog.display_simple();
+    ///     assert_eq!(dialog.accept(), allowed);
+    ///     dialog.close();
+    /// #   ctx.render();
+    /// }
+    /// ```
+    pub fn accept(&self) -> bool {
+        if !self.is_key_opened()
+            || self.current_filename(ResultMode::KeepInputFile).is_empty()
+            || !self.result_extension_allowed()
+        {
+            return false;
+        }
+        self.pending_result.set(Some(true));
+        true
+    }
+
+    /// Cancel the dialog from code, as if the user clicked Cancel.
+    ///
+    /// The next `display` returns `true` and `is_ok` reports `false` until the dialog is
+    /// closed. Does nothing if the dialog isn't open.
+    pub fn cancel(&self) {
+        if self.is_key_opened() {
+            self.pending_result.set(Some(false));
+        }
+    }
+
+    /// Closes the dialog.
+    ///
+    /// Receivers created with `result_sender` get the outcome of the dialog.
+    pub fn close(&self) {
+        let is_ok = self.result_accepted();
+        self.pending_result.set(None);
+        self.navigation_history.borrow_mut().clear();
+        self.navigation_path.replace(None);
+        if self.remember_last_path.get() && self.is_key_opened() {
+            self.last_path.replace(Some(self.current_path()));
+        }
+        self.close_reason.set(Some(if is_ok {
+            CloseReason::Ok
+        } else {
+            CloseReason::Cancelled
+        }));
+        self.send_outcome(is_ok);
+        unsafe {
+            backend::IGFD_CloseDialog(self.context.ptr);
+        }
+        self.context.active_dialog.replace(None);
+    }
+
+    /// Get a receiver for the outcome of each time the dialog is closed.
+    ///
+    /// `FileDialog` itself must stay on the ImGui thread, but the receiver and the
+    /// [`DialogOutcome`]s it gets are `Send`, so a worker thread can wait for a file pick
+    /// with `Receiver::recv`. The outcome is sent from `close` (and the helpers calling it,
+    /// like `take_result`), on the thread displaying the dialog. A dialog that is never
+    /// closed sends nothing, so make sure it gets closed once it returned a result.
+    pub fn result_sender(&self) -> mpsc::Receiver<DialogOutcome> {
+        let (sender, receiver) = mpsc::channel();
+        self.result_senders.borrow_mut().push(sender);
+        receiver
+    }
+
+    /// Send the outcome to the `result_sender` receivers, forgetting dropped ones
+    fn send_outcome(&self, is_ok: bool) {
+        let mut senders = self.result_senders.borrow_mut();
+        if senders.is_empty() {
+            return;
+        }
+        let outcome = if is_ok {
+            let mut paths = self.selection(ResultMode::default()).files();
+            if paths.is_empty() {
+                paths.push(PathBuf::from(
+                    self.current_file_path_name(ResultMode::default()),
+                ));
+            }
+            let paths: Result<Vec<_>, _> = paths
+                .into_iter()
+                .map(|path| self.prepare_result(path))
+                .collect();
+            match paths {
+                Ok(paths) => DialogOutcome::Ok(paths),
+                Err(err) => DialogOutcome::Failed(err),
+            }
+        } else {
+            DialogOutcome::Cancelled
+        };
+        senders.retain(|sender| sender.send(outcome.clone()).is_ok());
+    }
+
+    /// Returns whether the dialog was closed with OK button (or `accept`).
+    pub fn is_ok(&self) -> bool {
+        match self.pending_result.get() {
+            Some(is_ok) => is_ok,
+            None => unsafe { backend::IGFD_IsOk(self.context.ptr) },
+        }
+    }
+
+    /// Check that the dialog was confirmed with a result within the config's limits
+    fn result_accepted(&self) -> bool {
+        self.is_ok() && self.result_within_root() && self.result_extension_allowed()
+    }
+
+    /// Get the files the dialog returns: the selected ones, or the typed one if none is
+    fn result_paths(&self, mode: ResultMode) -> Vec<PathBuf> {
+        let mut paths = self.selection(mode).files();
+        if paths.is_empty() {
+            let file_path_name = self.current_file_path_name(mode);
+            if !file_path_name.is_empty() {
+                paths.push(PathBuf::from(file_path_name));
+            }
+        }
+        paths
+    }
+
+    /// Check the result against the config's `allowed_extensions`
+    fn result_extension_allowed(&self) -> bool {
+        let (allowed, case_insensitive) = match self.open_params.borrow().as_ref() {
+            Some(OpenParams {
+                config:
+                    FileDialogConfig {
+                        allowed_extensions: Some(allowed),
+                        flags,
+                        ..
+                    },
+                ..
+            }) => (
+                allowed.clone(),
+                flags.contains(FileDialogFlags::CASE_INSENSITIVE_EXTENSION_FILTERING),
+            ),
+            _ => return true,
+        };
+        self.result_paths(ResultMode::default()).iter().all(|path| {
+            allowed
+                .iter()
+                .any(|ext| matches_extension(path, ext, case_insensitive))
+        })
+    }
+
+    /// The configured `root_lock`, if the dialog was opened with one
+    fn root_lock(&self) -> Option<PathBuf> {
+        self.open_params
+            .borrow()
+            .as_ref()
+            .and_then(|params| params.config.root_lock.clone())
+    }
+
+    /// Check that the paths the dialog would return are within the `root_lock`
+    fn result_within_root(&self) -> bool {
+        let Some(root) = self.root_lock() else {
+            return true;
+        };
+        let mut paths = self.selection(ResultMode::default()).files();
+        if paths.is_empty() {
+            let file_path_name = self.current_file_path_name(ResultMode::default());
+            // directory mode has no file path name, its result is the current path
+            paths.push(PathBuf::from(if file_path_name.is_empty() {
+                self.current_path()
+            } else {
+                file_path_name
+            }));
+        }
+        paths.iter().all(|path| crate::util::is_within(path, &root))
+    }
+
+    /// Reopen the dialog at the `root_lock` if the user navigated outside of it
+    fn enforce_root_lock(&self) {
+        let Some(root) = self.root_lock() else {
+            return;
+        };
+        if !self.is_key_opened() {
+            return;
+        }
+        let current_path = self.current_path();
+        if current_path.is_empty() || crate::util::is_within(Path::new(&current_path), &root) {
+            return;
+        }
+        self.navigate_to(root.to_string_lossy().into_owned());
+    }
+
+    /// Reopen the open dialog at `path`, keeping the typed file name
+    fn navigate_to(&self, path: String) {
+        let Some(params) = self.open_params.borrow_mut().take() else {
+            return;
+        };
+        let config = FileDialogConfig {
+            path,
+            file_name: self.current_filename(ResultMode::KeepInputFile),
+            file_path_name: String::new(),
+            ..params.config
+        };
+        // ImGuiFileDialog ignores opening a dialog that is showing, close it first
+        unsafe { backend::IGFD_CloseDialog(self.context.ptr) };
+        self.open_impl(params.title, params.filters, config);
+    }
+
+    /// Get the directories the user navigated away from, oldest first.
+    ///
+    /// Recorded by `display` whenever the current path changed since the previous frame,
+    /// so a back button can be built with `go_back`. Only the last 32 paths are kept, and
+    /// the history is cleared when the dialog is closed. Paths outside of the config's
+    /// `root_lock` are left out.
+    pub fn navigation_history(&self) -> Vec<PathBuf> {
+        self.navigation_history
+            .borrow()
+            .iter()
+            .map(PathBuf::from)
+            .collect()
+    }
+
+    /// Navigate back to the last path of the `navigation_history`, removing it from there.
+    ///
+    /// ImGuiFileDialog can't change the path of an open dialog, so it's reopened there,
+    /// keeping the typed file name but not the selection or the scroll position. Returns
+    /// whether there was a path to go back to.
+    pub fn go_back(&self) -> bool {
+        if !self.is_key_opened() {
+            return false;
+        }
+        let Some(path) = self.navigation_history.borrow_mut().pop() else {
+            return false;
+        };
+        // going back isn't a navigation to record
+        self.navigation_path.replace(Some(Rc::from(path.as_str())));
+        self.navigate_to(path);
+        true
+    }
+
+    /// Record the previous path in the navigation history if the current path changed
+    fn track_navigation(&self, path: &Rc<str>) {
+        if path.is_empty() {
+            return;
+        }
+        let Some(previous) = self.navigation_path.replace(Some(path.clone())) else {
+            return;
+        };
+        if previous == *path {
+            return;
+        }
+        let outside_root = self
+            .root_lock()
+            .is_some_and(|root| !crate::util::is_within(Path::new(&*previous), &root));
+        if outside_root {
+            return;
+        }
+        let mut history = self.navigation_history.borrow_mut();
+        if history.len() == NAVIGATION_HISTORY_LEN {
+            history.remove(0);
+        }
+        history.push(previous.to_string());
+    }
+
+    /// Returns why the dialog was last closed.
+    ///
+    /// The reason is captured by `close` and kept until the dialog is opened again,
+    /// so it can still be queried in the frames following the close.
+    /// Returns `None` if the dialog hasn't been closed since it was last opened.
+    pub fn close_reason(&self) -> Option<CloseReason> {
+        self.close_reason.get()
+    }
+
+    /// Returns the message of the last error of this dialog, to show dialog problems in
+    /// one place.
+    ///
+    /// ImGuiFileDialog doesn't report errors to the caller (it prints a few, like a failed
+    /// directory creation, to stdout), so the errors are collected on the Rust side: from
+    /// the failed `try_*` calls of this dialog, and from `take_result` when the parent
+    /// directory of its result can't be created. `try_create` has no dialog to record on.
+    /// The message is kept until the next error or `clear_last_error`, successful calls
+    /// don't reset it.
+    ///
+    /// ```
+    /// # let mut ctx = imgui::Context::create();
+    /// # ctx.io_mut().display_size = [800.0, 600.0];
+    /// # ctx.fonts().build_rgba32_texture();
+    /// # ctx.set_ini_filename(None);
+    /// # let dialog = imgui_filedialog::FileDialog::create(ctx.new_frame(), "doc");
+    /// # ctx.render();
+    /// use imgui_filedialog::FileDialogConfig;
+    ///
+    /// assert_eq!(dialog.last_error(), None);
+    /// let _ = dialog.try_open("Open", Some("Images{.png"), FileDialogConfig::default());
+    /// assert!(dialog.last_error().unwrap().starts_with("invalid filters"));
+    ///
+    /// dialog.clear_last_error();
+    /// assert_eq!(dialog.last_error(), None);
+    /// ```
+    pub fn last_error(&self) -> Option<String> {
+        self.last_error.borrow().clone()
+    }
+
+    /// Forget the error returned by `last_error`, e.g. once it was shown to the user
+    pub fn clear_last_error(&self) {
+        self.last_error.replace(None);
+    }
+
+    /// Remember the message of a failed `result` for `last_error`
+    fn record_error<T, E: std::fmt::Display>(&self, result: Result<T, E>) -> Result<T, E> {
+        if let Err(err) = &result {
+            self.last_error.replace(Some(err.to_string()));
+        }
+        result
+    }
+
+    /// Returns whether the dialog window (or one of its child windows) has keyboard focus.
+    ///
+    /// Use this to suppress application hotkeys while the user interacts with the dialog.
+    /// With `NO_DIALOG` the dialog has no window of its own and this always returns
+    /// `false`; focus then follows the host window, so check `Ui::is_window_focused`
+    /// within it instead.
+    pub fn is_focused(&self) -> bool {
+        match self.window_name() {
+            Some(name) => unsafe { backend::IGFD_Ext_IsWindowFocused(name.as_ptr()) },
+            None => false,
+        }
+    }
+
+    /// Returns whether the dialog window is actually on screen.
+    ///
+    /// Unlike `is_opened`, which reports the logical open state, this is `false` while the
+    /// window is collapsed or hidden by ImGui, and before the dialog was displayed for the
+    /// first time. Being covered by other windows doesn't count as hidden.
+    /// With `NO_DIALOG` the dialog has no window of its own, so this falls back to
+    /// `is_key_opened`; whether it's on screen then depends on the host window.
+    pub fn is_visible(&self) -> bool {
+        if self.is_embedded() {
+            return self.is_key_opened();
+        }
+        match self.window_name() {
+            Some(name) => unsafe { backend::IGFD_Ext_IsWindowVisible(name.as_ptr()) },
+            None => false,
+        }
+    }
+
+    /// Get the dialog window's top-left and bottom-right corners in screen coordinates.
+    ///
+    /// Read it after `display`, e.g. to place custom overlays on top of the dialog; before
+    /// the first `display` of a frame it reports the previous frame's rect. Returns `None`
+    /// if the dialog isn't open or its window isn't on screen (like `is_visible`, this
+    /// includes the first frame, which ImGui uses to measure the window), and with
+    /// `NO_DIALOG`, where the dialog has no window of its own.
+    pub fn window_rect(&self) -> Option<(MintVec2, MintVec2)> {
+        if self.is_embedded() || !self.is_key_opened() {
+            return None;
+        }
+        let name = self.window_name()?;
+        let mut min = to_imvec2([0.0, 0.0]);
+        let mut max = to_imvec2([0.0, 0.0]);
+        if !unsafe { backend::IGFD_Ext_GetWindowRect(name.as_ptr(), &mut min, &mut max) } {
+            return None;
+        }
+        Some((from_imvec2(min), from_imvec2(max)))
+    }
+
+    /// Whether the dialog was opened with `NO_DIALOG` and thus has no window of its own
+    fn is_embedded(&self) -> bool {
+        self.open_params
+            .borrow()
+            .as_ref()
+            .is_some_and(|params| params.config.flags.contains(FileDialogFlags::NO_DIALOG))
+    }
+
+    /// Name of the ImGui window the dialog is displayed in, if it was opened
+    fn window_name(&self) -> Option<CString> {
+        let params = self.open_params.borrow();
+        let params = params.as_ref()?;
+        Some(CString::new(format!("{}##{}", params.title, self.id.to_str())).unwrap())
+    }
+
+    /// Returns whether the "overwrite existing file?" confirmation is showing.
+    ///
+    /// ImGuiFileDialog doesn't expose the confirmation state, and it can't be answered
+    /// programmatically. This checks ImGui's popup state for the confirmation popup by its
+    /// name instead, which is resolved against the current ID stack: call it from the
+    /// same place as `display`, e.g. right after it.
+    ///
+    /// The prompt is a modal popup of its own. With `MODAL`, ImGuiFileDialog shows the
+    /// dialog as a regular window while the prompt is up, so the prompt is the only modal
+    /// and blocks input to the dialog and the app alike. The detection relies on how
+    /// ImGuiFileDialog names the popup (its `OverWriteDialogTitleString`, the title and the
+    /// key), so it is fragile: it silently returns `false` if the bundled version changes
+    /// that naming or the title string is overridden at compile time.
+    pub fn is_overwrite_prompt_showing(&self) -> bool {
+        let params = self.open_params.borrow();
+        let Some(params) = params.as_ref() else {
+            return false;
+        };
+        let name = CString::new(format!(
+            "{}##{}{}OverWriteDialog",
+            OVERWRITE_DIALOG_TITLE,
+            params.title,
+            self.id.to_str()
+        ))
+        .unwrap();
+        // the docking bindings also cover imgui_internal.h, which overloads IsPopupOpen
+        #[cfg(not(feature = "docking"))]
+        use imgui::sys::igIsPopupOpen;
+        #[cfg(feature = "docking")]
+        use imgui::sys::igIsPopupOpen_Str as igIsPopupOpen;
+        unsafe { igIsPopupOpen(name.as_ptr(), 0) }
+    }
+
+    /// Returns whether the dialog was opened this frame.
+    pub fn was_opened_this_frame(&self) -> bool {
+        unsafe { backend::IGFD_WasOpenedThisFrame(self.context.ptr) }
+    }
+
+    /// Returns whether the dialog with this key was opened this frame.
+    pub fn was_key_opened_this_frame(&self) -> bool {
+        unsafe { backend::IGFD_WasKeyOpenedThisFrame(self.context.ptr, self.id.as_ptr()) }
+    }
+
+    /// Returns whether anything about the dialog changed this frame, e.g. to skip redundant
+    /// work in apps that only repaint on changes.
+    ///
+    /// Counts as a change:
+    /// - the dialog was opened this frame (`was_key_opened_this_frame`)
+    /// - `display` returned a result (OK or cancel) this frame
+    /// - the current path differs from the one seen at the previous call
+    /// - the selected files differ from the ones seen at the previous call
+    ///
+    /// Path and selection are compared between calls, so call this once per frame, after
+    /// `display`; further calls in the same frame return the same answer. A closed dialog
+    /// counts as having an empty path and selection, so closing it is a change as well.
+    /// Typing into the file name field or hovering entries doesn't count.
+    pub fn changed_this_frame(&self) -> bool {
+        let frame = unsafe { imgui::sys::igGetFrameCount() };
+        let mut snapshot = self.change_snapshot.borrow_mut();
+        if snapshot.frame == Some(frame) {
+            return snapshot.changed;
+        }
+
+        let (path, selection) = if self.is_key_opened() {
+            (
+                self.current_path(),
+                self.selection(ResultMode::KeepInputFile).files(),
+            )
+        } else {
+            (String::new(), Vec::new())
+        };
+        let changed = self.was_key_opened_this_frame()
+            || self.result_frame.get() == Some(frame)
+            || path != snapshot.path
+            || selection != snapshot.selection;
+        *snapshot = ChangeSnapshot {
+            frame: Some(frame),
+            changed,
+            path,
+            selection,
+        };
+        changed
+    }
+
+    /// Get a number that changes whenever the listed files may have changed, e.g. as a
+    /// cache key for views derived from the listing.
+    ///
+    /// The revision is bumped when the dialog is (re)opened, which scans the directory
+    /// again, and when the current path, the selected filter or the sort column or
+    /// direction changed since it was last checked. It is checked by `display` and by this
+    /// method, so changes are picked up once per frame while the dialog is displayed.
+    /// Changes on disk that ImGuiFileDialog doesn't see don't bump it.
+    pub fn listing_revision(&self) -> u64 {
+        if self.is_key_opened() {
+            self.update_listing_revision(&self.current_path_cached());
+        }
+        self.listing_revision.get()
+    }
+
+    /// Get the row of the selected file in the listing as displayed, e.g. to step to the
+    /// next or previous file of the folder.
+    ///
+    /// The index counts all displayed rows, including directories and the `..` entry, in
+    /// the current sort order and with the search and filters applied. With several
+    /// selected files it's the first of them. Returns `None` if the dialog isn't open,
+    /// nothing is selected or the selected file isn't listed (e.g. a name only typed into
+    /// the file name field). The C API has no such query, so this goes through a shim.
+    pub fn selected_index(&self) -> Option<usize> {
+        if !self.is_key_opened() {
+            return None;
         }
+        let mut index = 0;
+        unsafe { backend::IGFD_Ext_GetSelectedIndex(self.context.ptr, &mut index) }
+            .then_some(index as usize)
     }
 
-    /// Displays the dialog and returns true if a result was obtained (ok or not).
+    /// Get the names of the listed entries, in the order of the rows as displayed.
     ///
-    /// Arguments:
-    /// - `flags` - ImGui window flags
-    /// - `min_size` - Minimum window size
-    /// - `max_size` - Maximum window size
-    pub fn display(
-        &self,
-        flags: WindowFlags,
-        min_size: impl Into<MintVec2>,
-        max_size: impl Into<MintVec2>,
-    ) -> bool {
-        let min_size = min_size.into();
-        let max_size = max_size.into();
-        unsafe {
-            sys::IGFD_DisplayDialog(
-                self.context.ptr,
-                self.id.as_ptr(),
-                flags.bits() as sys::ImGuiWindowFlags,
-                sys::ImVec2 {
-                    x: min_size.x,
-                    y: min_size.y,
-                },
-                sys::ImVec2 {
-                    x: max_size.x,
-                    y: max_size.y,
-                },
-            )
+    /// Like `selected_index`, this covers all displayed rows, including directories and
+    /// the `..` entry, in the current sort order and with the search and filters applied,
+    /// so `listed_names()[index]` is the selected entry. Returns an empty list if the
+    /// dialog isn't open. The C API has no such query, so this goes through a shim.
+    pub fn listed_names(&self) -> Vec<String> {
+        if !self.is_key_opened() {
+            return Vec::new();
         }
+        (0..)
+            .map_while(|index| unsafe {
+                let ptr = backend::IGFD_Ext_GetListedFileName(self.context.ptr, index);
+                (!ptr.is_null()).then(|| ptr_clone_to_string(ptr))
+            })
+            .collect()
     }
 
-    /// Display the dialog with default parameters
-    pub fn display_simple(&self) -> bool {
-        self.display(
-            WindowFlags::NO_COLLAPSE,
-            MintVec2 { x: 200.0, y: 300.0 },
-            MintVec2 { x: 700.0, y: 500.0 },
-        )
+    /// Sort the file list by `column`, like clicking its header.
+    ///
+    /// The C API has no sorting entry point, so this goes through a shim. Directories stay
+    /// grouped apart from files: above them when sorting ascending, below them when
+    /// sorting descending. The order applies to all dialogs sharing the context and is kept
+    /// when navigating and reopening, until the user clicks a column header. The sort arrow
+    /// in the header isn't updated, it keeps marking the column the user last clicked.
+    pub fn set_sort(&self, column: SortColumn, ascending: bool) {
+        unsafe { backend::IGFD_Ext_SetSorting(self.context.ptr, column.field(), ascending) };
+        self.pending_sort.set(Some((column, ascending)));
     }
 
-    /// Closes the dialog.
-    pub fn close(&self) {
-        unsafe {
-            sys::IGFD_CloseDialog(self.context.ptr);
+    /// Sort again after the dialog was displayed, as the file table applies its own sort
+    /// order the first time it's shown
+    fn apply_pending_sort(&self) {
+        if let Some((column, ascending)) = self.pending_sort.take() {
+            unsafe { backend::IGFD_Ext_SetSorting(self.context.ptr, column.field(), ascending) };
         }
     }
 
-    /// Returns whether the dialog was closed with OK button.
-    pub fn is_ok(&self) -> bool {
-        unsafe { sys::IGFD_IsOk(self.context.ptr) }
+    /// Get the column the file list is sorted by, and whether it's sorted ascending
+    pub fn sort(&self) -> Option<(SortColumn, bool)> {
+        let mut field = 0;
+        let mut ascending = false;
+        let sorted =
+            unsafe { backend::IGFD_Ext_GetSorting(self.context.ptr, &mut field, &mut ascending) };
+        if !sorted {
+            return None;
+        }
+        SortColumn::from_field(field).map(|column| (column, ascending))
     }
 
-    /// Returns whether the dialog was opened this frame.
-    pub fn was_opened_this_frame(&self) -> bool {
-        unsafe { sys::IGFD_WasOpenedThisFrame(self.context.ptr) }
+    /// Scan the current directory again, e.g. after the app wrote files into it.
+    ///
+    /// ImGuiFileDialog's C API has no refresh, so this rescans through a shim instead of
+    /// navigating, which keeps the path, the typed file name, the filter, the search and
+    /// the scroll position. Selected files are kept by name, even if they were removed.
+    /// Bumps the `listing_revision`. Does nothing if the dialog isn't open.
+    pub fn refresh(&self) {
+        if !self.is_key_opened() {
+            return;
+        }
+        unsafe { backend::IGFD_Ext_Refresh(self.context.ptr) };
+        self.invalidate_listing();
     }
 
-    /// Returns whether the dialog with this key was opened this frame.
-    pub fn was_key_opened_this_frame(&self) -> bool {
-        unsafe { sys::IGFD_WasKeyOpenedThisFrame(self.context.ptr, self.id.as_ptr()) }
+    /// Bump the listing revision after the directory was scanned again
+    fn invalidate_listing(&self) {
+        self.listing_key.set(None);
+        self.listing_revision
+            .set(self.listing_revision.get().wrapping_add(1));
+    }
+
+    /// Bump the listing revision if what the listing depends on changed
+    fn update_listing_revision(&self, path: &str) {
+        let mut hasher = DefaultHasher::new();
+        path.hash(&mut hasher);
+        self.current_filter().hash(&mut hasher);
+        self.sort().hash(&mut hasher);
+        let key = hasher.finish();
+
+        if self
+            .listing_key
+            .replace(Some(key))
+            .is_some_and(|last| last != key)
+        {
+            self.listing_revision
+                .set(self.listing_revision.get().wrapping_add(1));
+        }
     }
 
     /// Returns whether the dialog is currently open
     pub fn is_opened(&self) -> bool {
-        unsafe { sys::IGFD_IsOpened(self.context.ptr) }
+        unsafe { backend::IGFD_IsOpened(self.context.ptr) }
     }
 
     /// Returns whether the dialog with this key is currently open
     pub fn is_key_opened(&self) -> bool {
-        unsafe { sys::IGFD_IsKeyOpened(self.context.ptr, self.id.as_ptr()) }
+        unsafe { backend::IGFD_IsKeyOpened(self.context.ptr, self.id.as_ptr()) }
     }
 
     /// Get the current path being browsed
     pub fn current_path(&self) -> String {
         unsafe {
-            let ptr = sys::IGFD_GetCurrentPath(self.context.ptr);
+            let ptr = backend::IGFD_GetCurrentPath(self.context.ptr);
             ptr_into_string(ptr)
         }
     }
 
+    /// Get the current path as a `PathBuf`, ready to be joined with a file name.
+    ///
+    /// ImGuiFileDialog may report the path with or without a trailing separator depending
+    /// on the platform and how the user navigated there. Trailing separators are stripped,
+    /// except for a root, which keeps exactly one: `/home/user/` becomes `/home/user`, `//`
+    /// becomes `/`, and on Windows `C:\\` becomes `C:\`.
+    pub fn current_path_buf(&self) -> PathBuf {
+        PathBuf::from(crate::util::trim_trailing_separators(&self.current_path()))
+    }
+
+    /// Get the root of the current path, e.g. for a breadcrumb bar.
+    ///
+    /// This is the drive (`C:\`, or `\\server\share` for network paths) on Windows and
+    /// `/` on Unix. ImGuiFileDialog reports absolute paths, but should the path be
+    /// relative, its first component is returned. Returns `None` only if the path is empty.
+    pub fn current_root(&self) -> Option<String> {
+        crate::util::path_root(&self.current_path())
+    }
+
+    /// Get the current path, re-using the previous value while it is unchanged.
+    ///
+    /// ImGuiFileDialog doesn't report navigation, so the path is queried at most once per
+    /// ImGui frame (and again after the dialog is opened); the cached string is only
+    /// replaced when the path actually changed. Calling this every frame therefore doesn't
+    /// allocate a new `String` each time, unlike `current_path`: the returned `Rc` shares
+    /// the cached string and can be kept as long as needed. `display` refreshes it, so
+    /// only calls before `display` in a frame return the path from before a navigation
+    /// done by that `display`.
+    pub fn current_path_cached(&self) -> Rc<str> {
+        let frame = unsafe { imgui::sys::igGetFrameCount() };
+        if self.current_path_frame.get() != Some(frame) {
+            return self.refresh_current_path();
+        }
+        self.current_path_cache.borrow().clone()
+    }
+
+    /// Query the current path into the `current_path_cached` cache and return it
+    fn refresh_current_path(&self) -> Rc<str> {
+        self.current_path_frame
+            .set(Some(unsafe { imgui::sys::igGetFrameCount() }));
+        let mut cache = self.current_path_cache.borrow_mut();
+        unsafe {
+            let ptr = backend::IGFD_GetCurrentPath(self.context.ptr);
+            if ptr.is_null() {
+                *cache = Rc::from("");
+            } else {
+                let path = CStr::from_ptr(ptr);
+                if path.to_bytes() != cache.as_bytes() {
+                    *cache = Rc::from(path.to_string_lossy());
+                }
+                imgui::sys::igMemFree(ptr as *mut c_void);
+            }
+        }
+        cache.clone()
+    }
+
+    /// Get the current path shortened to at most `max_chars` characters, for display.
+    ///
+    /// ImGuiFileDialog has no truncation option of its own. Long paths have their middle
+    /// replaced with `…`, keeping the start and the end of the path visible. Characters are
+    /// counted as `char`s, so multi-byte characters are never split. Use `current_path` for
+    /// the real path.
+    pub fn current_path_truncated(&self, max_chars: usize) -> String {
+        crate::util::ellipsize_middle(&self.current_path(), max_chars)
+    }
+
     /// Get the current filename
     pub fn current_filename(&self, mode: ResultMode) -> String {
         unsafe {
-            let ptr = sys::IGFD_GetCurrentFileName(self.context.ptr, mode as sys::IGFD_ResultMode);
+            let ptr =
+                backend::IGFD_GetCurrentFileName(self.context.ptr, mode as sys::IGFD_ResultMode);
             ptr_into_string(ptr)
         }
     }
 
+    /// Get the text typed into the file name field while the dialog is open.
+    ///
+    /// Returns `None` if the dialog isn't open or the field is empty. Read it before
+    /// calling `close`, e.g. after a cancel, to ask whether the typed name should be
+    /// discarded.
+    pub fn pending_filename(&self) -> Option<String> {
+        if !self.is_key_opened() {
+            return None;
+        }
+        Some(self.current_filename(ResultMode::KeepInputFile)).filter(|name| !name.is_empty())
+    }
+
+    /// Check whether the file name typed by the user has an extension.
+    ///
+    /// Looks at the raw field (`ResultMode::KeepInputFile`), so save dialogs can prompt
+    /// or append an extension themselves when the user typed e.g. `report`. See
+    /// [`has_extension`] for how names like `report.` are treated.
+    pub fn typed_has_extension(&self) -> bool {
+        has_extension(&self.current_filename(ResultMode::KeepInputFile))
+    }
+
     /// Get the current file path and name combined
     pub fn current_file_path_name(&self, mode: ResultMode) -> String {
         unsafe {
-            let ptr = sys::IGFD_GetFilePathName(self.context.ptr, mode as sys::IGFD_ResultMode);
+            let ptr = backend::IGFD_GetFilePathName(self.context.ptr, mode as sys::IGFD_ResultMode);
             ptr_into_string(ptr)
         }
     }
 
+    /// Check whether the file the dialog would return already exists on disk.
+    ///
+    /// Resolves the path like `current_file_path_name(mode)` does, so save dialogs can tell
+    /// overwriting a file from creating a new one. Returns `false` while no file name is
+    /// typed. ImGuiFileDialog asks for confirmation itself with `CONFIRM_OVERWRITE`, see
+    /// `is_overwrite_prompt_showing`.
+    pub fn target_exists(&self, mode: ResultMode) -> bool {
+        if self.current_filename(mode).is_empty() {
+            return false;
+        }
+        Path::new(&self.current_file_path_name(mode)).exists()
+    }
+
+    /// Check whether the user may create files in the current directory, e.g. to warn
+    /// about or refuse read-only locations in a save dialog.
+    ///
+    /// Asks the OS for the permissions without touching the directory: on Unix this honors
+    /// the user's permissions and read-only mounts, elsewhere only the read-only attribute
+    /// is checked. Use `probe_current_path_writable` where that isn't enough. The check is
+    /// best-effort and racy: permissions can change before the file is saved, so handle
+    /// write errors anyway.
+    pub fn current_path_writable(&self) -> bool {
+        crate::util::is_writable_dir(&self.current_path_buf())
+    }
+
+    /// Like `current_path_writable`, but checks by creating and removing an empty file in
+    /// the current directory.
+    ///
+    /// This catches what permissions don't tell, like Windows ACLs, full disks or `/proc`,
+    /// at the cost of a write to the filesystem on each call, so call it when needed (e.g.
+    /// when the user confirms) rather than every frame. It is just as racy.
+    pub fn probe_current_path_writable(&self) -> bool {
+        let path = self.current_path_buf();
+        path.is_dir() && crate::util::probe_writable_dir(&path)
+    }
+
     /// Get the current filter
     pub fn current_filter(&self) -> String {
         unsafe {
-            let ptr = sys::IGFD_GetCurrentFilter(self.context.ptr);
+            let ptr = backend::IGFD_GetCurrentFilter(self.context.ptr);
             ptr_into_string(ptr)
         }
     }
 
+    /// Get the path a save dialog should write to.
+    ///
+    /// Joins the current path with the typed file name, appending the primary extension
+    /// of the active filter if the name has none (a trailing `.` counts as none), like
+    /// `ResultMode::AddIfNoFileExt` does.
+    pub fn resolved_save_path(&self) -> PathBuf {
+        let file_name = self.current_filename(ResultMode::KeepInputFile);
+        let file_name = match self.current_filter_group() {
+            Some(group) => group.apply_result_mode(&file_name, ResultMode::AddIfNoFileExt),
+            None => file_name,
+        };
+        self.current_path_buf().join(file_name)
+    }
+
+    /// Find the parsed filter group the user has selected
+    fn current_filter_group(&self) -> Option<FilterGroup> {
+        let current_filter = self.current_filter();
+        Filters::parse(&self.filters()?)
+            .find(&current_filter)
+            .cloned()
+    }
+
+    /// Check that the files the dialog returns match the active filter.
+    ///
+    /// Typing a path into the file name field bypasses the filters, so after OK the result
+    /// can be any file. This checks each selected file (or the typed path if nothing is
+    /// selected), with `mode` applied, against the extensions of the selected filter
+    /// group, honoring `CASE_INSENSITIVE_EXTENSION_FILTERING`. Wildcard and regex filters
+    /// can't be checked and accept any file, as does a dialog without filters.
+    pub fn selection_matches_filter(&self, mode: ResultMode) -> bool {
+        let Some(group) = self.current_filter_group() else {
+            return true;
+        };
+        let case_insensitive = self.open_params.borrow().as_ref().is_some_and(|params| {
+            params
+                .config
+                .flags
+                .contains(FileDialogFlags::CASE_INSENSITIVE_EXTENSION_FILTERING)
+        });
+        self.result_paths(mode).iter().all(|path| {
+            group.extensions.iter().any(|ext| {
+                ext.contains('*')
+                    || ext.contains("((")
+                    || matches_extension(path, ext, case_insensitive)
+            })
+        })
+    }
+
     /// Get selected files (for multi-selection dialogs)
-    pub fn selection(&self, mode: ResultMode) -> Selection {
+    ///
+    /// The C API has no accessor for the selection count alone, so use
+    /// `selection(mode).count()` to get it. That copies the selected names once, which is
+    /// cheap enough to do every frame, e.g. to enable an OK button.
+    ///
+    /// `mode` is applied to each selected file exactly like `current_file_path_name`
+    /// applies it to the typed name, using the active filter, so single and multi-select
+    /// results agree. Only the full paths are affected: the file names of the entries are
+    /// the names as listed. The selection holds the files clicked in the list, a name
+    /// typed into the file name field is only returned by `current_file_path_name`.
+    pub fn selection(&self, mode: ResultMode) -> Selection<'_> {
         unsafe {
             Selection::new(
-                sys::IGFD_GetSelection(self.context.ptr, mode as sys::IGFD_ResultMode),
+                backend::IGFD_GetSelection(self.context.ptr, mode as sys::IGFD_ResultMode),
                 &self.context,
+                self.current_filter_group(),
             )
         }
     }
 
+    /// Check if more files are selected than the `count_selection_max` the dialog was last
+    /// opened with.
+    ///
+    /// ImGuiFileDialog enforces the limit itself, so this is a safeguard for showing a
+    /// "too many files selected" message. A limit of 0 means unlimited and always returns
+    /// `false`, as does a dialog that was never opened.
+    pub fn selection_over_limit(&self) -> bool {
+        let max = match self.open_params.borrow().as_ref() {
+            Some(params) => params.config.count_selection_max,
+            None => return false,
+        };
+        max > 0 && self.selection(ResultMode::KeepInputFile).count() > max as usize
+    }
+
+    /// Get the drives the user can browse with `SHOW_DEVICES_BUTTON`.
+    ///
+    /// The sys crate doesn't expose the dialog's device list, so this queries the
+    /// logical drives via the Win32 API directly; it lists the same drives the dialog shows.
+    #[cfg(windows)]
+    pub fn devices(&self) -> Vec<String> {
+        crate::util::logical_drives()
+    }
+
     /// Set custom file style by extension or criteria
     ///
     /// Arguments:
@@ -262,6 +2350,9 @@ impl FileDialog {
     /// - `criteria` - File extension or pattern to match
     /// - `color` - Color to use for matching files
     /// - `icon` - Optional icon text to display
+    ///
+    /// Styles can't carry a tooltip: the bundled ImGuiFileDialog (v0.6.8) only shows
+    /// per-file tooltips set from a file attributes callback, not per style.
     pub fn set_file_style(
         &self,
         flags: FileStyleFlags,
@@ -269,51 +2360,815 @@ impl FileDialog {
         color: impl Into<MintVec4>,
         icon: Option<impl Into<String>>,
     ) {
-        let criteria_cstr = CString::new(criteria.into()).unwrap();
-        let icon_cstr = icon.map(|i| CString::new(i.into()).unwrap());
-        let icon_ptr = icon_cstr.as_ref().map_or(std::ptr::null(), |c| c.as_ptr());
+        self.register_file_style(FileStyleRule {
+            flags,
+            criteria: criteria.into(),
+            color: color.into(),
+            icon: icon.map(Into::into),
+            font: None,
+        });
+    }
+
+    /// Like `set_file_style`, but fails if a color component is outside of `[0, 1]`.
+    ///
+    /// ImGui expects float colors in `[0, 1]`; values outside, typically 0-255 bytes passed
+    /// as floats, render wrongly. Use [`rgba8`] to convert byte colors. Nothing is
+    /// registered on error.
+    pub fn try_set_file_style(
+        &self,
+        flags: FileStyleFlags,
+        criteria: impl Into<String>,
+        color: impl Into<MintVec4>,
+        icon: Option<impl Into<String>>,
+    ) -> Result<(), FileDialogError> {
+        let color: [f32; 4] = color.into().into();
+        if !color.iter().all(|c| (0.0..=1.0).contains(c)) {
+            return self.record_error(Err(FileDialogError::InvalidColor(color)));
+        }
+        self.set_file_style(flags, criteria, color, icon);
+        Ok(())
+    }
+
+    /// Register several file styles, e.g. built with [`FileStyleRule::builder`]
+    pub fn set_file_styles(&self, rules: impl IntoIterator<Item = FileStyleRule>) {
+        for rule in rules {
+            self.register_file_style(rule);
+        }
+    }
 
-        let color = color.into();
+    fn register_file_style(&self, rule: FileStyleRule) {
+        let criteria_cstr = CString::new(rule.criteria.as_str()).unwrap();
+        let icon_cstr = rule.icon.as_deref().map(|i| CString::new(i).unwrap());
+        let icon_ptr = icon_cstr.as_ref().map_or(std::ptr::null(), |c| c.as_ptr());
+        let font_ptr = rule.font.map_or(std::ptr::null_mut(), atlas_font);
 
         unsafe {
-            sys::IGFD_SetFileStyle2(
+            backend::IGFD_SetFileStyle2(
                 self.context.ptr,
-                flags.bits() as sys::IGFD_FileStyleFlags,
+                rule.flags.bits() as sys::IGFD_FileStyleFlags,
                 criteria_cstr.as_ptr(),
-                color.x,
-                color.y,
-                color.z,
-                color.w,
+                rule.color.x,
+                rule.color.y,
+                rule.color.z,
+                rule.color.w,
                 icon_ptr,
-                std::ptr::null_mut(), // font
+                font_ptr,
             );
         }
+
+        // the dialog replaces styles registered for the same flags and criteria
+        let mut file_styles = self.context.file_styles.borrow_mut();
+        file_styles.retain(|other| other.flags != rule.flags || other.criteria != rule.criteria);
+        file_styles.push(rule);
+    }
+
+    /// Get all file styles registered on this dialog's context, in registration order
+    pub fn file_styles(&self) -> Vec<FileStyleRule> {
+        self.context.file_styles.borrow().clone()
+    }
+
+    /// Get the file style registered for the given flags and criteria
+    ///
+    /// Arguments:
+    /// - `flags` - What type of files the style was registered for
+    /// - `criteria` - File extension or pattern, as passed to `set_file_style`
+    pub fn get_file_style(
+        &self,
+        flags: FileStyleFlags,
+        criteria: impl Into<String>,
+    ) -> Option<FileStyle> {
+        let criteria_cstr = CString::new(criteria.into()).unwrap();
+        let mut color = sys::ImVec4 {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+            w: 0.0,
+        };
+        let mut icon_ptr: *mut std::os::raw::c_char = std::ptr::null_mut();
+
+        let found = unsafe {
+            backend::IGFD_GetFileStyle(
+                self.context.ptr,
+                flags.bits() as sys::IGFD_FileStyleFlags,
+                criteria_cstr.as_ptr(),
+                &mut color,
+                &mut icon_ptr,
+                std::ptr::null_mut(), // font
+            )
+        };
+        let icon = unsafe { ptr_into_string(icon_ptr) };
+
+        if !found {
+            return None;
+        }
+        Some(FileStyle {
+            color: from_imvec4(color),
+            icon: if icon.is_empty() { None } else { Some(icon) },
+        })
+    }
+
+    /// Get the icon the dialog would display for the given file.
+    ///
+    /// Looks up a style registered for the full file name first, then for its extension.
+    pub fn icon_for(&self, path: &Path) -> Option<String> {
+        let by_name = path.file_name().and_then(|name| {
+            self.get_file_style(FileStyleFlags::BY_FULL_NAME, name.to_string_lossy())
+        });
+        let style = by_name.or_else(|| {
+            path.extension().and_then(|ext| {
+                self.get_file_style(
+                    FileStyleFlags::BY_EXTENSION,
+                    format!(".{}", ext.to_string_lossy()),
+                )
+            })
+        })?;
+        style.icon
+    }
+
+    /// Set icons shown for files and directories no other style matches.
+    ///
+    /// Registers `BY_TYPE_FILE` and `BY_TYPE_DIR` styles with an empty criteria, colored
+    /// like the current text color of the ImGui style so only the icon changes.
+    /// ImGuiFileDialog uses the first style that matches a file and doesn't define in which
+    /// order style kinds are checked; with libstdc++ it checks the most recently added kind
+    /// first. To keep the type-level styles as a fallback, the styles registered so far are
+    /// cleared and registered again after them.
+    pub fn set_default_icons(&self, file_icon: &str, dir_icon: &str) {
+        let text_color = unsafe {
+            *imgui::sys::igGetStyleColorVec4(imgui::sys::ImGuiCol_Text as imgui::sys::ImGuiCol)
+        };
+        let color = MintVec4 {
+            x: text_color.x,
+            y: text_color.y,
+            z: text_color.z,
+            w: text_color.w,
+        };
+
+        let is_default = |rule: &FileStyleRule| {
+            rule.criteria.is_empty()
+                && (rule.flags == FileStyleFlags::BY_TYPE_FILE
+                    || rule.flags == FileStyleFlags::BY_TYPE_DIR)
+        };
+        let mut rules = self.file_styles();
+        rules.retain(|rule| !is_default(rule));
+
+        self.clear_file_styles();
+        self.set_file_style(FileStyleFlags::BY_TYPE_FILE, "", color, Some(file_icon));
+        self.set_file_style(FileStyleFlags::BY_TYPE_DIR, "", color, Some(dir_icon));
+        self.set_file_styles(rules);
+    }
+
+    /// Replace all file styles of this dialog's context with the rules of `theme`.
+    ///
+    /// The current styles are cleared first, so rules that aren't part of the theme don't
+    /// linger. Like every style, the theme applies to all dialogs sharing the context.
+    pub fn apply_theme(&self, theme: &FileTheme) {
+        self.clear_file_styles();
+        self.set_file_styles(theme.rules.iter().cloned());
+    }
+
+    /// Capture the file styles registered on this dialog's context as a theme, e.g. to
+    /// restore them later with `apply_theme` or to persist them with the `serde` feature
+    pub fn capture_theme(&self) -> FileTheme {
+        FileTheme {
+            rules: self.file_styles(),
+        }
     }
 
     /// Clear all custom file styles
     pub fn clear_file_styles(&self) {
         unsafe {
-            sys::IGFD_ClearFilesStyle(self.context.ptr);
+            backend::IGFD_ClearFilesStyle(self.context.ptr);
         }
+        self.context.file_styles.borrow_mut().clear();
     }
 
     /// Set locale for the dialog
     ///
     /// Arguments:
-    /// - `category` - Locale category (e.g., LC_ALL)
+    /// - `category` - Locale category, a [`LocaleCategory`] or a raw `LC_*` value
     /// - `begin_locale` - Locale to use at beginning of dialog display
     /// - `end_locale` - Locale to use at end of dialog display
-    pub fn set_locales(&self, category: i32, begin_locale: &str, end_locale: &str) {
+    pub fn set_locales(&self, category: impl Into<i32>, begin_locale: &str, end_locale: &str) {
         let begin_cstr = CString::new(begin_locale).unwrap();
         let end_cstr = CString::new(end_locale).unwrap();
 
         unsafe {
-            sys::SetLocales(
+            backend::SetLocales(
                 self.context.ptr,
-                category,
+                category.into(),
                 begin_cstr.as_ptr(),
                 end_cstr.as_ptr(),
             );
         }
     }
 }
+
+/// Called by ImGuiFileDialog for each listed file of a dialog with an entry filter
+unsafe extern "C" fn entry_filter_trampoline(
+    path: *const c_char,
+    file_name: *const c_char,
+    user_data: *mut c_void,
+) -> bool {
+    // user_data is the EntryFilter kept alive by Context::active_entry_filter
+    let filter = &*(user_data as *const RefCell<Box<dyn FnMut(&Path) -> bool>>);
+    let path = Path::new(&*CStr::from_ptr(path).to_string_lossy())
+        .join(&*CStr::from_ptr(file_name).to_string_lossy());
+    // show the file on panic
+    ffi_guard("entry filter", true, || match filter.try_borrow_mut() {
+        Ok(mut filter) => filter(&path),
+        Err(_) => true,
+    })
+}
+
+// FileDialog and Context wrap a raw pointer to a C++ object that must only be touched from
+// the ImGui thread. These checks fail to compile (ambiguous impl) should either type ever
+// become Send or Sync, e.g. after swapping the raw pointer for a wrapper type.
+macro_rules! assert_not_send_sync {
+    ($($ty:ty),*) => {
+        const _: fn() = || {
+            trait AmbiguousIfSend<A> {
+                fn some_item() {}
+            }
+            impl<T: ?Sized> AmbiguousIfSend<()> for T {}
+            impl<T: ?Sized + Send> AmbiguousIfSend<u8> for T {}
+
+            trait AmbiguousIfSync<A> {
+                fn some_item() {}
+            }
+            impl<T: ?Sized> AmbiguousIfSync<()> for T {}
+            impl<T: ?Sized + Sync> AmbiguousIfSync<u8> for T {}
+
+            $(
+                let _ = <$ty as AmbiguousIfSend<_>>::some_item;
+                let _ = <$ty as AmbiguousIfSync<_>>::some_item;
+            )*
+        };
+    };
+}
+
+assert_not_send_sync!(FileDialog, Context);
+
+// every public config/flag type must stay inspectable under the bevy_reflect feature
+#[cfg(feature = "bevy_reflect")]
+const _: fn() = || {
+    fn assert_reflect<T: bevy_reflect::Reflect>() {}
+    assert_reflect::<FileDialogConfig>();
+    assert_reflect::<FileDialogFlags>();
+    assert_reflect::<FileStyleFlags>();
+    assert_reflect::<ResultMode>();
+    assert_reflect::<CloseReason>();
+    assert_reflect::<DialogMode>();
+    assert_reflect::<Column>();
+    assert_reflect::<LocaleCategory>();
+    assert_reflect::<StandardDir>();
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::headless;
+
+    #[cfg(not(feature = "mock"))]
+    #[test]
+    fn set_filters_reopens_open_dialog() {
+        let mut imgui = headless();
+        let dialog = imgui.dialog("filters");
+        dialog.open("Open", Some(".txt"), FileDialogConfig::default());
+        imgui.frame(|_| dialog.display_simple());
+        assert_eq!(dialog.current_filter(), ".txt");
+
+        dialog.set_filters(Some(".png,.jpg"));
+        imgui.frame(|_| dialog.display_simple());
+        assert!(dialog.is_key_opened());
+        assert_eq!(dialog.current_filter(), ".png");
+        assert_eq!(dialog.filters().as_deref(), Some(".png,.jpg"));
+    }
+
+    #[cfg(not(feature = "mock"))]
+    #[test]
+    fn set_entry_filter_replaces_filter_of_open_dialog() {
+        use std::cell::Cell;
+
+        let dir = std::env::temp_dir().join("imgui-filedialog-replace-entry-filter");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("file.txt"), "").unwrap();
+
+        let mut imgui = headless();
+        let dialog = imgui.dialog("entry_filter");
+        let old_calls = Rc::new(Cell::new(0));
+        let new_calls = Rc::new(Cell::new(0));
+        let counter = old_calls.clone();
+        dialog.set_entry_filter(move |_| {
+            counter.set(counter.get() + 1);
+            true
+        });
+        let config = FileDialogConfig {
+            path: dir.display().to_string(),
+            ..Default::default()
+        };
+        dialog.open("Open", Some(".txt"), config);
+        imgui.frame(|_| dialog.display_simple());
+        assert!(old_calls.get() > 0);
+
+        let counter = new_calls.clone();
+        dialog.set_entry_filter(move |_| {
+            counter.set(counter.get() + 1);
+            true
+        });
+        imgui.frame(|_| dialog.display_simple());
+        dialog.refresh();
+        // the old filter was released and ImGuiFileDialog calls the new one
+        assert_eq!(Rc::strong_count(&old_calls), 1);
+        assert!(new_calls.get() > 0);
+    }
+
+    #[cfg(not(feature = "mock"))]
+    #[test]
+    fn ignored_open_keeps_entry_filter_alive() {
+        use std::cell::Cell;
+
+        let dir = std::env::temp_dir().join("imgui-filedialog-shared-entry-filter");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("file.txt"), "").unwrap();
+
+        let mut imgui = headless();
+        let (context, first, second) = {
+            let ui = imgui.ctx.new_frame();
+            let context = Rc::new(Context::new(ui));
+            let first = FileDialog::with_context(ui, context.clone(), "first");
+            let second = FileDialog::with_context(ui, context.clone(), "second");
+            (context, first, second)
+        };
+        imgui.ctx.render();
+
+        let calls = Rc::new(Cell::new(0));
+        let counter = calls.clone();
+        first.set_entry_filter(move |_| {
+            counter.set(counter.get() + 1);
+            true
+        });
+        let config = FileDialogConfig {
+            path: dir.display().to_string(),
+            ..Default::default()
+        };
+        first.open("Open", Some(".txt"), config);
+        imgui.frame(|_| first.display_simple());
+
+        // ignored by ImGuiFileDialog, as the first dialog is showing
+        second.open("Open", None::<String>, FileDialogConfig::default());
+        assert!(first.is_key_opened());
+        // ImGuiFileDialog still calls the first dialog's filter, e.g. when a dialog
+        // recreated with the same id rescans
+        drop(first);
+        assert_eq!(Rc::strong_count(&calls), 2);
+        let recreated = {
+            let ui = imgui.ctx.new_frame();
+            FileDialog::with_context(ui, context, "first")
+        };
+        imgui.ctx.render();
+        let before = calls.get();
+        recreated.refresh();
+        assert!(calls.get() > before);
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn take_result_closes_once_after_ok() {
+        let mut imgui = headless();
+        crate::mock::reset();
+        let dialog = imgui.dialog("take_result");
+        dialog.open("Open", Some(".txt"), FileDialogConfig::default());
+        crate::mock::set_current_path("/data");
+        crate::mock::set_file_name("notes.txt");
+        crate::mock::set_result(true, true);
+
+        let path = imgui.frame(|_| dialog.take_result(WindowFlags::empty(), [0.0; 2], [0.0; 2]));
+        assert_eq!(path, Some(PathBuf::from("/data/notes.txt")));
+        assert_eq!(crate::mock::call_count("IGFD_CloseDialog"), 1);
+        assert_eq!(dialog.close_reason(), Some(CloseReason::Ok));
+
+        // the closed dialog has no result to take anymore
+        let path = imgui.frame(|_| dialog.take_result(WindowFlags::empty(), [0.0; 2], [0.0; 2]));
+        assert_eq!(path, None);
+        assert_eq!(crate::mock::call_count("IGFD_CloseDialog"), 1);
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn take_result_closes_once_after_cancel() {
+        let mut imgui = headless();
+        crate::mock::reset();
+        let dialog = imgui.dialog("take_result");
+        dialog.open("Open", None::<String>, FileDialogConfig::default());
+        crate::mock::set_result(true, false);
+
+        let path = imgui.frame(|_| dialog.take_result(WindowFlags::empty(), [0.0; 2], [0.0; 2]));
+        assert_eq!(path, None);
+        assert_eq!(crate::mock::call_count("IGFD_CloseDialog"), 1);
+        assert_eq!(dialog.close_reason(), Some(CloseReason::Cancelled));
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn display_before_open_reports_nothing() {
+        let mut imgui = headless();
+        crate::mock::reset();
+        let dialog = imgui.dialog("unopened");
+        crate::mock::set_result(true, true);
+
+        assert!(!imgui.frame(|_| dialog.display_simple()));
+        assert_eq!(crate::mock::call_count("IGFD_CloseDialog"), 0);
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn set_filters_closes_before_reopening() {
+        let mut imgui = headless();
+        crate::mock::reset();
+        let dialog = imgui.dialog("filters");
+        dialog.open("Open", Some(".txt"), FileDialogConfig::default());
+        crate::mock::clear_calls();
+
+        dialog.set_filters(Some(".png"));
+        let calls = crate::mock::calls();
+        let close = calls.iter().position(|call| *call == "IGFD_CloseDialog");
+        let open = calls.iter().position(|call| *call == "IGFD_OpenDialog");
+        assert!(close.is_some() && close < open, "{:?}", calls);
+        imgui.frame(|_| dialog.display_simple());
+        assert!(dialog.is_key_opened());
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn set_column_visibility_applies_to_open_dialog() {
+        let mut imgui = headless();
+        crate::mock::reset();
+        let dialog = imgui.dialog("columns");
+        dialog.open("Open", None::<String>, FileDialogConfig::default());
+        imgui.frame(|_| dialog.display_simple());
+        let flags = crate::mock::opened_flags().unwrap();
+        assert!(!flags.contains(FileDialogFlags::HIDE_COLUMN_SIZE));
+
+        dialog.set_column_visibility(Column::Size, false);
+        imgui.frame(|_| dialog.display_simple());
+        let flags = crate::mock::opened_flags().unwrap();
+        assert!(flags.contains(FileDialogFlags::HIDE_COLUMN_SIZE));
+        assert!(dialog.is_key_opened());
+
+        dialog.set_column_visibility(Column::Size, true);
+        let flags = crate::mock::opened_flags().unwrap();
+        assert!(!flags.contains(FileDialogFlags::HIDE_COLUMN_SIZE));
+    }
+
+    #[test]
+    fn try_create_rejects_empty_id() {
+        let mut imgui = headless();
+        let ui = imgui.ctx.new_frame();
+        assert!(matches!(
+            FileDialog::try_create(ui, ""),
+            Err(FileDialogError::EmptyId)
+        ));
+        let dialog = FileDialog::try_create(ui, "named").unwrap();
+        assert_eq!(dialog.id(), "named");
+        imgui.ctx.render();
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn set_default_icons_registers_fallback_styles_first() {
+        let mut imgui = headless();
+        crate::mock::reset();
+        let dialog = imgui.dialog("default_icons");
+        dialog.set_file_style(FileStyleFlags::BY_EXTENSION, ".png", [1.0; 4], Some("P"));
+
+        imgui.frame(|_| dialog.set_default_icons("F", "D"));
+        let styles = crate::mock::file_styles();
+        let registered: Vec<_> = styles
+            .iter()
+            .map(|(flags, criteria, icon)| (*flags, criteria.as_str(), icon.as_deref()))
+            .collect();
+        assert_eq!(
+            registered,
+            [
+                (FileStyleFlags::BY_TYPE_FILE, "", Some("F")),
+                (FileStyleFlags::BY_TYPE_DIR, "", Some("D")),
+                (FileStyleFlags::BY_EXTENSION, ".png", Some("P")),
+            ]
+        );
+        assert_eq!(crate::mock::call_count("IGFD_ClearFilesStyle"), 1);
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn selection_matches_filter_checks_the_selected_group() {
+        let mut imgui = headless();
+        crate::mock::reset();
+        let dialog = imgui.dialog("matches_filter");
+        dialog.open(
+            "Open",
+            Some("Images{.png,.jpg},.txt"),
+            FileDialogConfig::default(),
+        );
+        crate::mock::set_filter("Images");
+        crate::mock::set_selection(&[("a.png", "/data/a.png"), ("b.jpg", "/data/b.jpg")]);
+        assert!(dialog.selection_matches_filter(ResultMode::KeepInputFile));
+
+        crate::mock::set_selection(&[("a.png", "/data/a.png"), ("notes.txt", "/data/notes.txt")]);
+        assert!(!dialog.selection_matches_filter(ResultMode::KeepInputFile));
+
+        // without a selection, the typed path is checked
+        crate::mock::set_selection(&[]);
+        crate::mock::set_current_path("/data");
+        crate::mock::set_file_name("notes.txt");
+        assert!(!dialog.selection_matches_filter(ResultMode::KeepInputFile));
+        crate::mock::set_filter(".txt");
+        assert!(dialog.selection_matches_filter(ResultMode::KeepInputFile));
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn try_set_file_style_rejects_out_of_range_colors() {
+        let mut imgui = headless();
+        crate::mock::reset();
+        let dialog = imgui.dialog("styles");
+        for color in [
+            [255.0, 0.0, 0.0, 255.0],
+            [-0.1, 0.0, 0.0, 1.0],
+            [f32::NAN, 0.0, 0.0, 1.0],
+        ] {
+            let result = dialog.try_set_file_style(
+                FileStyleFlags::BY_EXTENSION,
+                ".png",
+                color,
+                None::<String>,
+            );
+            assert!(matches!(result, Err(FileDialogError::InvalidColor(_))));
+        }
+        assert_eq!(crate::mock::call_count("IGFD_SetFileStyle2"), 0);
+        assert!(dialog.file_styles().is_empty());
+
+        dialog
+            .try_set_file_style(
+                FileStyleFlags::BY_EXTENSION,
+                ".png",
+                [1.0, 0.0, 0.0, 1.0],
+                None::<String>,
+            )
+            .unwrap();
+        assert_eq!(crate::mock::call_count("IGFD_SetFileStyle2"), 1);
+    }
+
+    #[cfg(not(feature = "mock"))]
+    #[test]
+    fn set_sort_orders_the_listing() {
+        let dir = std::env::temp_dir().join("imgui-filedialog-set-sort");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("a.txt"), "a much larger file").unwrap();
+        std::fs::write(dir.join("b.txt"), "").unwrap();
+        std::fs::write(dir.join("c.txt"), "small").unwrap();
+
+        let mut imgui = headless();
+        let dialog = imgui.dialog("sort");
+        let config = FileDialogConfig {
+            path: dir.display().to_string(),
+            ..Default::default()
+        };
+        dialog.set_sort(SortColumn::Size, false);
+        dialog.open("Open", Some(".txt"), config);
+        imgui.frame(|_| dialog.display_simple());
+        assert_eq!(dialog.sort(), Some((SortColumn::Size, false)));
+        assert_eq!(dialog.listed_names(), ["a.txt", "c.txt", "b.txt", ".."]);
+
+        dialog.set_sort(SortColumn::Name, false);
+        imgui.frame(|_| dialog.display_simple());
+        assert_eq!(dialog.sort(), Some((SortColumn::Name, false)));
+        assert_eq!(dialog.listed_names(), ["c.txt", "b.txt", "a.txt", ".."]);
+
+        // directories are listed above the files when sorting ascending
+        dialog.set_sort(SortColumn::Name, true);
+        imgui.frame(|_| dialog.display_simple());
+        assert_eq!(dialog.listed_names(), ["..", "a.txt", "b.txt", "c.txt"]);
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn current_path_cached_can_be_kept_across_frames() {
+        let mut imgui = headless();
+        crate::mock::reset();
+        let dialog = imgui.dialog("cached_path");
+        crate::mock::set_current_path("/data");
+        let first = imgui.frame(|_| dialog.current_path_cached());
+        assert_eq!(&*first, "/data");
+
+        // the cache is refreshed while the previous value is still held
+        crate::mock::set_current_path("/data/images");
+        let second = imgui.frame(|_| dialog.current_path_cached());
+        assert_eq!(&*first, "/data");
+        assert_eq!(&*second, "/data/images");
+
+        // an unchanged path shares the cached string
+        let third = imgui.frame(|_| dialog.current_path_cached());
+        assert!(Rc::ptr_eq(&second, &third));
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn single_selection_matches_current_file_path_name() {
+        let mut imgui = headless();
+        crate::mock::reset();
+        let dialog = imgui.dialog("single_selection");
+        dialog.open(
+            "Open",
+            Some("Archives{.tar.gz,.zip},.txt"),
+            FileDialogConfig::default(),
+        );
+        crate::mock::set_filter("Archives");
+        crate::mock::set_current_path("/data");
+        for name in [
+            "backup",
+            "backup.",
+            "backup.zip",
+            "backup.old.zip",
+            "backup.tar.gz",
+        ] {
+            let path = format!("/data/{}", name);
+            crate::mock::set_file_name(name);
+            crate::mock::set_selection(&[(name, &path)]);
+            for mode in ResultMode::ALL {
+                let expected = vec![PathBuf::from(dialog.current_file_path_name(mode))];
+                let selection = dialog.selection(mode);
+                assert_eq!(selection.files(), expected, "{} with {:?}", name, mode);
+                assert_eq!(
+                    selection.formatted_paths(mode),
+                    expected,
+                    "{} with {:?}",
+                    name,
+                    mode
+                );
+                assert_eq!(
+                    dialog
+                        .selection(ResultMode::KeepInputFile)
+                        .formatted_paths(mode),
+                    expected,
+                    "{} with {:?}",
+                    name,
+                    mode
+                );
+            }
+        }
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn save_as_prefills_the_primary_extension() {
+        let mut imgui = headless();
+        let dialog = imgui.dialog("save_as");
+        let cases = [
+            ("Images{.png,.jpg},Text{.txt}", "untitled.png"),
+            ("All{.*},Images{.png}", "untitled"),
+            ("((.*\\.png)),.txt", "untitled"),
+            ("Archives{.tar.gz,.zip},.txt", "untitled.tar.gz"),
+        ];
+        for (filter_string, file_name) in cases {
+            crate::mock::reset();
+            let filters = Filters::parse(filter_string);
+            dialog.open(
+                "Save",
+                Some(filters.to_string()),
+                FileDialogConfig::save_as("untitled", &filters),
+            );
+            assert_eq!(
+                crate::mock::opened_filters().as_deref(),
+                Some(filter_string)
+            );
+            assert_eq!(crate::mock::opened_file_name(), file_name);
+        }
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn root_lock_rejects_results_outside_the_root() {
+        let root = std::env::temp_dir().join("imgui-filedialog-root-lock-result");
+        std::fs::create_dir_all(&root).unwrap();
+        let root = root.canonicalize().unwrap();
+        let outside = root.parent().unwrap().join("outside.txt");
+
+        let mut imgui = headless();
+        crate::mock::reset();
+        let dialog = imgui.dialog("root_lock_result");
+        let outcomes = dialog.result_sender();
+        let config = FileDialogConfig {
+            path: root.display().to_string(),
+            root_lock: Some(root.clone()),
+            ..Default::default()
+        };
+        dialog.open("Open", None::<String>, config);
+        crate::mock::set_current_path(&root.to_string_lossy());
+        crate::mock::set_file_name("outside.txt");
+        crate::mock::set_selection(&[("outside.txt", &outside.to_string_lossy())]);
+        crate::mock::set_result(true, true);
+
+        // the dialog's own result is reported as is, but not returned
+        let path = imgui
+            .frame(|_| dialog.take_result(WindowFlags::empty(), [0.0, 0.0], [f32::MAX, f32::MAX]));
+        assert_eq!(path, None);
+        assert!(dialog.is_ok());
+        assert!(dialog.is_key_opened());
+
+        dialog.close();
+        assert_eq!(outcomes.try_recv().unwrap(), DialogOutcome::Cancelled);
+        assert_eq!(dialog.close_reason(), Some(CloseReason::Cancelled));
+
+        // within the root, the result is returned
+        let inside = root.join("inside.txt");
+        dialog.open(
+            "Open",
+            None::<String>,
+            FileDialogConfig {
+                root_lock: Some(root.clone()),
+                ..Default::default()
+            },
+        );
+        crate::mock::set_file_name("inside.txt");
+        crate::mock::set_selection(&[("inside.txt", &inside.to_string_lossy())]);
+        let path = imgui
+            .frame(|_| dialog.take_result(WindowFlags::empty(), [0.0, 0.0], [f32::MAX, f32::MAX]));
+        assert_eq!(path, Some(inside.clone()));
+        assert_eq!(
+            outcomes.try_recv().unwrap(),
+            DialogOutcome::Ok(vec![inside])
+        );
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn allowed_extensions_only_limit_the_returned_result() {
+        let mut imgui = headless();
+        crate::mock::reset();
+        let dialog = imgui.dialog("allowed_extensions");
+        let outcomes = dialog.result_sender();
+        let config = FileDialogConfig {
+            allowed_extensions: Some(vec![".png".to_string()]),
+            ..Default::default()
+        };
+        dialog.open("Save", None::<String>, config);
+        crate::mock::set_current_path("/data");
+        crate::mock::set_file_name("notes.txt");
+        crate::mock::set_result(true, true);
+
+        // display and is_ok report what the dialog reports
+        assert!(imgui.frame(|_| dialog.display_simple()));
+        assert!(dialog.is_ok());
+
+        let path = imgui
+            .frame(|_| dialog.take_result(WindowFlags::empty(), [0.0, 0.0], [f32::MAX, f32::MAX]));
+        assert_eq!(path, None);
+        assert!(dialog.is_key_opened());
+        assert!(outcomes.try_recv().is_err());
+
+        crate::mock::set_file_name("image.png");
+        let path = imgui
+            .frame(|_| dialog.take_result(WindowFlags::empty(), [0.0, 0.0], [f32::MAX, f32::MAX]));
+        let expected = Path::new("/data").join("image.png");
+        assert_eq!(path, Some(expected.clone()));
+        assert_eq!(
+            outcomes.try_recv().unwrap(),
+            DialogOutcome::Ok(vec![expected])
+        );
+    }
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn display_queries_the_current_path_once() {
+        let mut imgui = headless();
+        crate::mock::reset();
+        let dialog = imgui.dialog("path_once");
+        dialog.open("Open", None::<String>, FileDialogConfig::default());
+        crate::mock::set_current_path("/data");
+        imgui.frame(|_| dialog.display_simple());
+
+        crate::mock::clear_calls();
+        crate::mock::set_current_path("/data/images");
+        let path = imgui.frame(|_| {
+            dialog.display_simple();
+            dialog.listing_revision();
+            dialog.current_path_cached()
+        });
+        assert_eq!(crate::mock::call_count("IGFD_GetCurrentPath"), 1);
+        assert_eq!(&*path, "/data/images");
+        assert_eq!(dialog.navigation_history(), [PathBuf::from("/data")]);
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn devices_lists_drive_roots() {
+        let mut imgui = headless();
+        let dialog = imgui.dialog("devices");
+        let devices = dialog.devices();
+        assert!(!devices.is_empty());
+        for device in &devices {
+            assert!(device.ends_with(":\\"), "{}", device);
+        }
+    }
+}
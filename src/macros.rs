@@ -0,0 +1,9 @@
+/// Declares a public config/flag type, deriving `bevy_reflect::Reflect` for it when the
+/// `bevy_reflect` feature is enabled. Use this for every such type so none misses the derive.
+macro_rules! reflectable {
+    ($(#[$meta:meta])* $vis:vis $kind:ident $name:ident $($rest:tt)*) => {
+        $(#[$meta])*
+        #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+        $vis $kind $name $($rest)*
+    };
+}
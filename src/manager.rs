@@ -0,0 +1,95 @@
+use crate::{Context, FileDialog};
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+/// Creates file dialogs and keeps track of which of them are open.
+///
+/// Each dialog gets a [`Context`] of its own, so unlike dialogs sharing a context, any
+/// number of them can be open at the same time. The manager doesn't keep the dialogs
+/// alive: a dropped dialog releases its context and is no longer tracked.
+///
+/// ```
+/// # let mut ctx = imgui::Context::create();
+/// # ctx.io_mut().display_size = [800.0, 600.0];
+/// # ctx.fonts().build_rgba32_texture();
+/// # let ui = ctx.new_frame();
+/// use imgui_filedialog::FileDialogManager;
+///
+/// let manager = FileDialogManager::new();
+/// let import = manager.create(ui, "import");
+/// let export = manager.create(ui, "export");
+///
+/// import.open("Import", None::<String>, Default::default());
+/// export.open("Export", None::<String>, Default::default());
+/// assert_eq!(manager.active_dialogs(), ["import", "export"]);
+///
+/// import.close();
+/// assert_eq!(manager.active_dialogs(), ["export"]);
+/// ```
+#[derive(Default)]
+pub struct FileDialogManager {
+    // contexts of the dialogs created so far, in creation order
+    contexts: RefCell<Vec<Weak<Context>>>,
+}
+
+impl FileDialogManager {
+    /// Create a manager without any dialogs
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new file dialog with the given identifier, tracked by this manager
+    pub fn create(&self, ui: &mut imgui::Ui, id: &str) -> FileDialog {
+        let context = Rc::new(Context::new(ui));
+        let mut contexts = self.contexts.borrow_mut();
+        contexts.retain(|context| context.strong_count() > 0);
+        contexts.push(Rc::downgrade(&context));
+        FileDialog::with_context(ui, context, id)
+    }
+
+    /// Get the ids of the dialogs of this manager that are currently open, in creation
+    /// order.
+    ///
+    /// Like `Context::active_dialog`, this is tracked as the dialogs are opened and
+    /// closed: a dialog is listed from `open` until `close`, also after the user pressed
+    /// OK or Cancel. A dialog that stays listed long after it was used hints at a missing
+    /// `close`.
+    pub fn active_dialogs(&self) -> Vec<String> {
+        self.contexts
+            .borrow()
+            .iter()
+            .filter_map(Weak::upgrade)
+            .filter_map(|context| context.active_dialog())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::headless;
+    use crate::FileDialogConfig;
+
+    #[test]
+    fn active_dialogs_lists_open_dialogs() {
+        let mut imgui = headless();
+        let manager = FileDialogManager::new();
+        let (first, second) = {
+            let ui = imgui.ctx.new_frame();
+            (manager.create(ui, "first"), manager.create(ui, "second"))
+        };
+        imgui.ctx.render();
+        assert!(manager.active_dialogs().is_empty());
+
+        first.open("First", None::<String>, FileDialogConfig::default());
+        second.open("Second", None::<String>, FileDialogConfig::default());
+        assert_eq!(manager.active_dialogs(), ["first", "second"]);
+
+        first.close();
+        assert_eq!(manager.active_dialogs(), ["second"]);
+
+        // a dropped dialog is no longer tracked
+        drop(second);
+        assert!(manager.active_dialogs().is_empty());
+    }
+}
@@ -0,0 +1,485 @@
+//! Recording stand-in for the ImGuiFileDialog FFI, enabled by the `mock` feature.
+//!
+//! With the feature on, every call this crate would make into ImGuiFileDialog is recorded
+//! here instead, and the dialog state it reports (open state, OK result, paths, selection)
+//! is scripted through the setters below. This lets tests of code built on [`FileDialog`]
+//! assert which calls were made without a GPU or a real dialog:
+//!
+//! ```
+//! # let mut ctx = imgui::Context::create();
+//! # ctx.io_mut().display_size = [800.0, 600.0];
+//! # ctx.fonts().build_rgba32_texture();
+//! # ctx.set_ini_filename(None);
+//! # let dialog = imgui_filedialog::FileDialog::create(ctx.new_frame(), "doc");
+//! # ctx.render();
+//! imgui_filedialog::mock::reset();
+//! dialog.open("Open", None::<String>, Default::default());
+//! imgui_filedialog::mock::set_result(true, true);
+//! # let _ui = ctx.new_frame();
+//! if dialog.display_simple() {
+//!     dialog.close();
+//! }
+//! # ctx.render();
+//! assert_eq!(imgui_filedialog::mock::call_count("IGFD_CloseDialog"), 1);
+//! ```
+//!
+//! A `FileDialog` still needs an `imgui::Ui` to be created, so tests need a (headless)
+//! imgui context. State is thread-local, so tests running in parallel don't interfere.
+//! Which dialog is open is tracked per [`Context`], all other state is shared.
+//!
+//! [`FileDialog`]: crate::FileDialog
+//! [`Context`]: crate::Context
+
+use std::cell::RefCell;
+use std::collections::hash_map::{Entry, HashMap};
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int, c_void};
+
+#[derive(Default)]
+struct MockState {
+    calls: Vec<&'static str>,
+    // key of the open dialog of each context, by context address
+    opened_keys: HashMap<usize, CString>,
+    opened_flags: Option<crate::FileDialogFlags>,
+    opened_filters: Option<String>,
+    opened_file_name: String,
+    display_result: bool,
+    is_ok: bool,
+    current_path: String,
+    file_name: String,
+    filter: String,
+    selection: Vec<(String, String)>,
+    sorting: Option<(c_int, bool)>,
+    file_styles: Vec<(crate::FileStyleFlags, String, Option<String>)>,
+}
+
+thread_local! {
+    static STATE: RefCell<MockState> = RefCell::new(MockState::default());
+}
+
+fn with_state<R>(f: impl FnOnce(&mut MockState) -> R) -> R {
+    STATE.with(|state| f(&mut state.borrow_mut()))
+}
+
+fn record(call: &'static str) {
+    with_state(|state| state.calls.push(call));
+}
+
+/// Reset the recorded calls and all scripted state
+pub fn reset() {
+    with_state(|state| *state = MockState::default());
+}
+
+/// Get the names of the FFI functions called so far, in call order
+pub fn calls() -> Vec<&'static str> {
+    with_state(|state| state.calls.clone())
+}
+
+/// Get how often the FFI function `name` was called
+pub fn call_count(name: &str) -> usize {
+    with_state(|state| state.calls.iter().filter(|call| **call == name).count())
+}
+
+/// Forget the recorded calls, keeping the scripted state
+pub fn clear_calls() {
+    with_state(|state| state.calls.clear());
+}
+
+/// Get the flags of the last open that took effect, i.e. that wasn't ignored because a
+/// dialog was open already
+pub fn opened_flags() -> Option<crate::FileDialogFlags> {
+    with_state(|state| state.opened_flags)
+}
+
+/// Get the filter string of the last open that took effect, `None` for dialogs opened
+/// without filters
+pub fn opened_filters() -> Option<String> {
+    with_state(|state| state.opened_filters.clone())
+}
+
+/// Get the file name the last open that took effect prefilled, empty if none
+pub fn opened_file_name() -> String {
+    with_state(|state| state.opened_file_name.clone())
+}
+
+/// Get the file styles registered since the last clear as `(flags, criteria, icon)`, in
+/// registration order
+pub fn file_styles() -> Vec<(crate::FileStyleFlags, String, Option<String>)> {
+    with_state(|state| state.file_styles.clone())
+}
+
+/// Script what displaying an open dialog returns, and whether the result is OK
+pub fn set_result(display_result: bool, is_ok: bool) {
+    with_state(|state| {
+        state.display_result = display_result;
+        state.is_ok = is_ok;
+    });
+}
+
+/// Script the path the dialog reports as current
+pub fn set_current_path(path: &str) {
+    with_state(|state| state.current_path = path.to_string());
+}
+
+/// Script the file name the dialog reports as typed
+pub fn set_file_name(file_name: &str) {
+    with_state(|state| state.file_name = file_name.to_string());
+}
+
+/// Script the label of the filter the dialog reports as selected
+pub fn set_filter(filter: &str) {
+    with_state(|state| state.filter = filter.to_string());
+}
+
+/// Script the selection as `(file name, file path name)` pairs
+pub fn set_selection(selection: &[(&str, &str)]) {
+    with_state(|state| {
+        state.selection = selection
+            .iter()
+            .map(|(name, path)| (name.to_string(), path.to_string()))
+            .collect()
+    });
+}
+
+/// Open the dialog `key` unless a dialog of the context is open, like ImGuiFileDialog does
+unsafe fn open(
+    ctx: *mut sys::ImGuiFileDialog,
+    key: &CStr,
+    filters: *const c_char,
+    config: &sys::IGFD_FileDialog_Config,
+) {
+    with_state(|state| {
+        if let Entry::Vacant(entry) = state.opened_keys.entry(ctx as usize) {
+            entry.insert(key.to_owned());
+            state.opened_flags = Some(crate::FileDialogFlags::from_bits_truncate(
+                config.flags as sys::ImGuiFileDialogFlags_,
+            ));
+            state.opened_filters = (!filters.is_null())
+                .then(|| CStr::from_ptr(filters).to_string_lossy().into_owned());
+            state.opened_file_name = if config.fileName.is_null() {
+                String::new()
+            } else {
+                CStr::from_ptr(config.fileName)
+                    .to_string_lossy()
+                    .into_owned()
+            };
+        }
+    });
+}
+
+/// Apply `mode` to a file name for the selected filter, like ImGuiFileDialog does for
+/// both the typed name and each selected file
+fn apply_result_mode(state: &MockState, file_name: &str, mode: sys::IGFD_ResultMode) -> String {
+    let filters = crate::Filters::parse(state.opened_filters.as_deref().unwrap_or_default());
+    let mode = crate::ResultMode::ALL
+        .iter()
+        .copied()
+        .find(|m| *m as sys::IGFD_ResultMode == mode)
+        .unwrap_or_default();
+    match filters.find(&state.filter) {
+        Some(group) => group.apply_result_mode(file_name, mode),
+        None => file_name.to_string(),
+    }
+}
+
+/// Copy a string into memory freed by `util::ptr_into_string`, like the C API does
+fn alloc_string(s: &str) -> *mut c_char {
+    if s.is_empty() {
+        return std::ptr::null_mut();
+    }
+    let c_string = CString::new(s).unwrap();
+    let bytes = c_string.as_bytes_with_nul();
+    unsafe {
+        let ptr = imgui::sys::igMemAlloc(bytes.len()) as *mut c_char;
+        std::ptr::copy_nonoverlapping(bytes.as_ptr() as *const c_char, ptr, bytes.len());
+        ptr
+    }
+}
+
+/// Mock versions of the sys functions used by this crate, see `backend`
+#[allow(non_snake_case)]
+pub(crate) mod ffi {
+    use super::*;
+
+    pub unsafe fn IGFD_Create() -> *mut sys::ImGuiFileDialog {
+        record("IGFD_Create");
+        // a distinct address per context, to track their open dialogs apart
+        Box::into_raw(Box::new(0u8)) as *mut sys::ImGuiFileDialog
+    }
+
+    pub unsafe fn IGFD_Destroy(ctx: *mut sys::ImGuiFileDialog) {
+        record("IGFD_Destroy");
+        with_state(|state| state.opened_keys.remove(&(ctx as usize)));
+        drop(Box::from_raw(ctx as *mut u8));
+    }
+
+    pub unsafe fn IGFD_OpenDialog(
+        ctx: *mut sys::ImGuiFileDialog,
+        key: *const c_char,
+        _title: *const c_char,
+        filters: *const c_char,
+        config: sys::IGFD_FileDialog_Config,
+    ) {
+        record("IGFD_OpenDialog");
+        open(ctx, CStr::from_ptr(key), filters, &config);
+    }
+
+    pub unsafe fn IGFD_Ext_OpenDialogWithEntryFilter(
+        ctx: *mut sys::ImGuiFileDialog,
+        key: *const c_char,
+        _title: *const c_char,
+        filters: *const c_char,
+        config: sys::IGFD_FileDialog_Config,
+        _entry_filter: sys::IGFD_Ext_EntryFilterFun,
+        _entry_filter_user_datas: *mut c_void,
+    ) {
+        record("IGFD_Ext_OpenDialogWithEntryFilter");
+        open(ctx, CStr::from_ptr(key), filters, &config);
+    }
+
+    pub unsafe fn IGFD_DisplayDialog(
+        ctx: *mut sys::ImGuiFileDialog,
+        key: *const c_char,
+        _flags: sys::ImGuiWindowFlags,
+        _min_size: sys::ImVec2,
+        _max_size: sys::ImVec2,
+    ) -> bool {
+        record("IGFD_DisplayDialog");
+        let key = CStr::from_ptr(key);
+        with_state(|state| {
+            state
+                .opened_keys
+                .get(&(ctx as usize))
+                .map(CString::as_c_str)
+                == Some(key)
+                && state.display_result
+        })
+    }
+
+    pub unsafe fn IGFD_CloseDialog(ctx: *mut sys::ImGuiFileDialog) {
+        record("IGFD_CloseDialog");
+        with_state(|state| state.opened_keys.remove(&(ctx as usize)));
+    }
+
+    pub unsafe fn IGFD_IsOk(_ctx: *mut sys::ImGuiFileDialog) -> bool {
+        record("IGFD_IsOk");
+        with_state(|state| state.is_ok)
+    }
+
+    pub unsafe fn IGFD_WasKeyOpenedThisFrame(
+        _ctx: *mut sys::ImGuiFileDialog,
+        _key: *const c_char,
+    ) -> bool {
+        record("IGFD_WasKeyOpenedThisFrame");
+        false
+    }
+
+    pub unsafe fn IGFD_WasOpenedThisFrame(_ctx: *mut sys::ImGuiFileDialog) -> bool {
+        record("IGFD_WasOpenedThisFrame");
+        false
+    }
+
+    pub unsafe fn IGFD_IsKeyOpened(ctx: *mut sys::ImGuiFileDialog, key: *const c_char) -> bool {
+        record("IGFD_IsKeyOpened");
+        let key = CStr::from_ptr(key);
+        with_state(|state| {
+            state
+                .opened_keys
+                .get(&(ctx as usize))
+                .map(CString::as_c_str)
+                == Some(key)
+        })
+    }
+
+    pub unsafe fn IGFD_IsOpened(ctx: *mut sys::ImGuiFileDialog) -> bool {
+        record("IGFD_IsOpened");
+        with_state(|state| state.opened_keys.contains_key(&(ctx as usize)))
+    }
+
+    pub unsafe fn IGFD_GetSelection(
+        _ctx: *mut sys::ImGuiFileDialog,
+        mode: sys::IGFD_ResultMode,
+    ) -> sys::IGFD_Selection {
+        record("IGFD_GetSelection");
+        let pairs: Box<[sys::IGFD_Selection_Pair]> = with_state(|state| {
+            state
+                .selection
+                .iter()
+                .map(|(name, path)| {
+                    let mut path = std::path::PathBuf::from(path);
+                    if !name.is_empty() && path.file_name().is_some() {
+                        path.set_file_name(apply_result_mode(state, name, mode));
+                    }
+                    sys::IGFD_Selection_Pair {
+                        fileName: alloc_string(name),
+                        filePathName: alloc_string(&path.to_string_lossy()),
+                    }
+                })
+                .collect()
+        });
+        let count = pairs.len();
+        sys::IGFD_Selection {
+            table: Box::into_raw(pairs) as *mut sys::IGFD_Selection_Pair,
+            count: count as sys::size_t,
+        }
+    }
+
+    pub unsafe fn IGFD_Selection_DestroyContent(selection: *mut sys::IGFD_Selection) {
+        record("IGFD_Selection_DestroyContent");
+        let selection = &mut *selection;
+        if selection.table.is_null() {
+            return;
+        }
+        let pairs = Box::from_raw(std::ptr::slice_from_raw_parts_mut(
+            selection.table,
+            selection.count as usize,
+        ));
+        for pair in pairs.iter() {
+            imgui::sys::igMemFree(pair.fileName as *mut c_void);
+            imgui::sys::igMemFree(pair.filePathName as *mut c_void);
+        }
+        selection.table = std::ptr::null_mut();
+        selection.count = 0;
+    }
+
+    pub unsafe fn IGFD_GetFilePathName(
+        _ctx: *mut sys::ImGuiFileDialog,
+        mode: sys::IGFD_ResultMode,
+    ) -> *mut c_char {
+        record("IGFD_GetFilePathName");
+        with_state(|state| {
+            let file_name = apply_result_mode(state, &state.file_name, mode);
+            let path = std::path::Path::new(&state.current_path).join(file_name);
+            alloc_string(&path.to_string_lossy())
+        })
+    }
+
+    pub unsafe fn IGFD_GetCurrentFileName(
+        _ctx: *mut sys::ImGuiFileDialog,
+        mode: sys::IGFD_ResultMode,
+    ) -> *mut c_char {
+        record("IGFD_GetCurrentFileName");
+        with_state(|state| alloc_string(&apply_result_mode(state, &state.file_name, mode)))
+    }
+
+    pub unsafe fn IGFD_GetCurrentPath(_ctx: *mut sys::ImGuiFileDialog) -> *mut c_char {
+        record("IGFD_GetCurrentPath");
+        with_state(|state| alloc_string(&state.current_path))
+    }
+
+    pub unsafe fn IGFD_GetCurrentFilter(_ctx: *mut sys::ImGuiFileDialog) -> *mut c_char {
+        record("IGFD_GetCurrentFilter");
+        with_state(|state| alloc_string(&state.filter))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub unsafe fn IGFD_SetFileStyle2(
+        _ctx: *mut sys::ImGuiFileDialog,
+        flags: sys::IGFD_FileStyleFlags,
+        criteria: *const c_char,
+        _r: f32,
+        _g: f32,
+        _b: f32,
+        _a: f32,
+        icon: *const c_char,
+        _font: *mut sys::ImFont,
+    ) {
+        record("IGFD_SetFileStyle2");
+        let flags = crate::FileStyleFlags::from_bits_truncate(flags as sys::IGFD_FileStyleFlags_);
+        let criteria = CStr::from_ptr(criteria).to_string_lossy().into_owned();
+        let icon = (!icon.is_null()).then(|| CStr::from_ptr(icon).to_string_lossy().into_owned());
+        with_state(|state| state.file_styles.push((flags, criteria, icon)));
+    }
+
+    pub unsafe fn IGFD_GetFileStyle(
+        _ctx: *mut sys::ImGuiFileDialog,
+        _flags: sys::IGFD_FileStyleFlags,
+        _criteria: *const c_char,
+        _out_color: *mut sys::ImVec4,
+        _out_icon: *mut *mut c_char,
+        _out_font: *mut *mut sys::ImFont,
+    ) -> bool {
+        record("IGFD_GetFileStyle");
+        false
+    }
+
+    pub unsafe fn IGFD_ClearFilesStyle(_ctx: *mut sys::ImGuiFileDialog) {
+        record("IGFD_ClearFilesStyle");
+        with_state(|state| state.file_styles.clear());
+    }
+
+    pub unsafe fn SetLocales(
+        _ctx: *mut sys::ImGuiFileDialog,
+        _category: c_int,
+        _begin_locale: *const c_char,
+        _end_locale: *const c_char,
+    ) {
+        record("SetLocales");
+    }
+
+    pub unsafe fn IGFD_Ext_IsWindowFocused(_window_name: *const c_char) -> bool {
+        record("IGFD_Ext_IsWindowFocused");
+        false
+    }
+
+    pub unsafe fn IGFD_Ext_IsWindowVisible(_window_name: *const c_char) -> bool {
+        record("IGFD_Ext_IsWindowVisible");
+        false
+    }
+
+    pub unsafe fn IGFD_Ext_GetSorting(
+        _ctx: *mut sys::ImGuiFileDialog,
+        out_field: *mut c_int,
+        out_ascending: *mut bool,
+    ) -> bool {
+        record("IGFD_Ext_GetSorting");
+        match with_state(|state| state.sorting) {
+            Some((field, ascending)) => {
+                *out_field = field;
+                *out_ascending = ascending;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub unsafe fn IGFD_Ext_SetSorting(
+        _ctx: *mut sys::ImGuiFileDialog,
+        field: c_int,
+        ascending: bool,
+    ) {
+        record("IGFD_Ext_SetSorting");
+        if (1..=4).contains(&field) {
+            with_state(|state| state.sorting = Some((field, ascending)));
+        }
+    }
+
+    pub unsafe fn IGFD_Ext_GetSelectedIndex(
+        _ctx: *mut sys::ImGuiFileDialog,
+        _out_index: *mut sys::size_t,
+    ) -> bool {
+        record("IGFD_Ext_GetSelectedIndex");
+        false
+    }
+
+    pub unsafe fn IGFD_Ext_GetListedFileName(
+        _ctx: *mut sys::ImGuiFileDialog,
+        _index: sys::size_t,
+    ) -> *const c_char {
+        record("IGFD_Ext_GetListedFileName");
+        std::ptr::null()
+    }
+
+    pub unsafe fn IGFD_Ext_Refresh(_ctx: *mut sys::ImGuiFileDialog) {
+        record("IGFD_Ext_Refresh");
+    }
+
+    pub unsafe fn IGFD_Ext_GetWindowRect(
+        _window_name: *const c_char,
+        _out_min: *mut sys::ImVec2,
+        _out_max: *mut sys::ImVec2,
+    ) -> bool {
+        record("IGFD_Ext_GetWindowRect");
+        false
+    }
+}
@@ -0,0 +1,76 @@
+//! Standard user directories, e.g. to seed `FileDialogConfig::path`.
+//!
+//! With the `dirs` feature the directories are looked up with the `dirs` crate, which
+//! follows each platform's conventions (XDG user dirs, Windows known folders, ...).
+//! Without it, they are derived from the home directory (`HOME`, or `USERPROFILE` on
+//! Windows) as `Documents` and `Downloads`, and only returned if they exist.
+
+use std::path::PathBuf;
+
+reflectable! {
+    /// A standard user directory, see `FileDialogConfig::path_dir`
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum StandardDir {
+        /// The user's home directory
+        Home,
+        /// The user's documents directory
+        Documents,
+        /// The user's downloads directory
+        Downloads,
+    }
+}
+
+impl StandardDir {
+    /// Get the path of this directory, if it can be determined
+    pub fn path(self) -> Option<PathBuf> {
+        match self {
+            Self::Home => home_dir(),
+            Self::Documents => documents_dir(),
+            Self::Downloads => downloads_dir(),
+        }
+    }
+}
+
+/// Get the user's home directory
+#[cfg(feature = "dirs")]
+pub fn home_dir() -> Option<PathBuf> {
+    dirs::home_dir()
+}
+
+/// Get the user's documents directory
+#[cfg(feature = "dirs")]
+pub fn documents_dir() -> Option<PathBuf> {
+    dirs::document_dir()
+}
+
+/// Get the user's downloads directory
+#[cfg(feature = "dirs")]
+pub fn downloads_dir() -> Option<PathBuf> {
+    dirs::download_dir()
+}
+
+/// Get the user's home directory
+#[cfg(not(feature = "dirs"))]
+pub fn home_dir() -> Option<PathBuf> {
+    let var = if cfg!(windows) { "USERPROFILE" } else { "HOME" };
+    std::env::var_os(var)
+        .filter(|home| !home.is_empty())
+        .map(PathBuf::from)
+}
+
+/// Get the user's documents directory
+#[cfg(not(feature = "dirs"))]
+pub fn documents_dir() -> Option<PathBuf> {
+    home_subdir("Documents")
+}
+
+/// Get the user's downloads directory
+#[cfg(not(feature = "dirs"))]
+pub fn downloads_dir() -> Option<PathBuf> {
+    home_subdir("Downloads")
+}
+
+#[cfg(not(feature = "dirs"))]
+fn home_subdir(name: &str) -> Option<PathBuf> {
+    Some(home_dir()?.join(name)).filter(|dir| dir.is_dir())
+}
@@ -1,51 +1,225 @@
+use crate::backend;
+use crate::filters::FilterGroup;
 use crate::util::ptr_clone_to_string;
-use crate::Context;
-use std::collections::HashMap;
-use std::path::PathBuf;
+use crate::{Context, ResultMode};
+use std::cell::OnceCell;
+use std::collections::{HashMap, HashSet};
+use std::ffi::CStr;
+use std::iter::FromIterator;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// A single entry of the user's file selection
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SelectionEntry {
+    /// File name of the entry
+    pub file_name: String,
+    /// Full path of the entry
+    pub file_path_name: PathBuf,
+}
+
+impl SelectionEntry {
+    /// Check if this entry is a directory.
+    ///
+    /// The C selection table only carries file names and paths, so this asks the
+    /// filesystem via [`Path::is_dir`](std::path::Path::is_dir). Entries that don't
+    /// exist (e.g. a new file name typed into a save dialog) report `false`.
+    pub fn is_directory(&self) -> bool {
+        self.file_path_name.is_dir()
+    }
+}
+
+/// A selected file along with its filesystem metadata
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SelectionMeta {
+    /// Full path of the file
+    pub path: PathBuf,
+    /// Last modification time, if available
+    pub modified: Option<SystemTime>,
+    /// Size in bytes, if available
+    pub size: Option<u64>,
+}
 
 /// Represents the user's file selection
 pub struct Selection<'ui> {
     ptr: sys::IGFD_Selection,
     _context: &'ui Context,
+    paths: OnceCell<Vec<PathBuf>>,
+    filter: Option<FilterGroup>,
 }
 
 impl<'ui> Selection<'ui> {
-    pub(crate) fn new(ptr: sys::IGFD_Selection, context: &'ui Context) -> Self {
+    pub(crate) fn new(
+        ptr: sys::IGFD_Selection,
+        context: &'ui Context,
+        filter: Option<FilterGroup>,
+    ) -> Self {
         Selection {
             ptr,
             _context: context,
+            paths: OnceCell::new(),
+            filter,
         }
     }
 
+    /// Iterate over the raw entries of the C selection table
+    fn pairs(&self) -> impl Iterator<Item = &sys::IGFD_Selection_Pair> {
+        let pairs: &[sys::IGFD_Selection_Pair] = if self.ptr.table.is_null() {
+            &[]
+        } else {
+            unsafe { std::slice::from_raw_parts(self.ptr.table, self.ptr.count as usize) }
+        };
+        pairs.iter()
+    }
+
     /// Get selected files as a vector of PathBuf
     pub fn files(&self) -> Vec<PathBuf> {
-        let mut ret = Vec::new();
-        for i in 0..self.ptr.count {
-            unsafe {
-                let file_path =
-                    ptr_clone_to_string((*self.ptr.table.offset(i as isize)).filePathName);
-                if !file_path.is_empty() {
-                    ret.push(PathBuf::from(file_path));
+        self.pairs()
+            .filter_map(|pair| {
+                let file_path = unsafe { ptr_clone_to_string(pair.filePathName) };
+                (!file_path.is_empty()).then(|| PathBuf::from(file_path))
+            })
+            .collect()
+    }
+
+    /// Collect the selected files into any collection of paths, in the order of `files`.
+    ///
+    /// ```
+    /// # let mut ctx = imgui::Context::create();
+    /// # ctx.io_mut().display_size = [800.0, 600.0];
+    /// # ctx.fonts().build_rgba32_texture();
+    /// # ctx.set_ini_filename(None);
+    /// # let dialog = imgui_filedialog::FileDialog::create(ctx.new_frame(), "doc");
+    /// # ctx.render();
+    /// # #[cfg(feature = "mock")]
+    /// # imgui_filedialog::mock::set_selection(&[
+    /// #     ("b.txt", "/data/b.txt"),
+    /// #     ("a.txt", "/data/a.txt"),
+    /// #     ("b.txt", "/data/b.txt"),
+    /// # ]);
+    /// use imgui_filedialog::ResultMode;
+    /// use std::collections::BTreeSet;
+    /// use std::path::{Path, PathBuf};
+    ///
+    /// // sorted, without duplicates
+    /// let files: BTreeSet<PathBuf> = dialog.selection(ResultMode::default()).collect_into();
+    /// # #[cfg(feature = "mock")]
+    /// assert!(files.iter().eq([Path::new("/data/a.txt"), Path::new("/data/b.txt")]));
+    /// ```
+    pub fn collect_into<B: FromIterator<PathBuf>>(&self) -> B {
+        self.files().into_iter().collect()
+    }
+
+    /// Get selected files like `files`, with duplicate paths removed.
+    ///
+    /// The first occurrence of each path is kept, so the order of `files` is preserved.
+    /// ImGuiFileDialog keys its selection by file name, so this is a safeguard against
+    /// processing a file twice rather than something expected to happen.
+    pub fn unique_files(&self) -> Vec<PathBuf> {
+        let mut seen = HashSet::new();
+        self.files()
+            .into_iter()
+            .filter(|path| seen.insert(path.clone()))
+            .collect()
+    }
+
+    /// Iterate over the selected files without cloning them.
+    ///
+    /// The first call converts the C strings once and caches the paths in this
+    /// `Selection`; later calls borrow from that cache.
+    pub fn paths(&self) -> impl Iterator<Item = &Path> {
+        self.paths
+            .get_or_init(|| self.files())
+            .iter()
+            .map(PathBuf::as_path)
+    }
+
+    /// Get selected entries with both file name and full path
+    ///
+    /// The path has the `ResultMode` passed to `FileDialog::selection` applied, the file
+    /// name is the name as listed in the dialog.
+    pub fn entries(&self) -> Vec<SelectionEntry> {
+        self.pairs()
+            .filter_map(|pair| unsafe {
+                let file_name = ptr_clone_to_string(pair.fileName);
+                let file_path = ptr_clone_to_string(pair.filePathName);
+                (!file_path.is_empty()).then(|| SelectionEntry {
+                    file_name,
+                    file_path_name: PathBuf::from(file_path),
+                })
+            })
+            .collect()
+    }
+
+    /// Get selected files with `mode`'s extension handling applied to each file name.
+    ///
+    /// Uses the same logic as single-file results and `FileDialog::resolved_save_path`,
+    /// based on the filter that was active when this selection was taken.
+    /// `files` already honors the mode passed to `FileDialog::selection`; this re-applies
+    /// another mode without querying the dialog again.
+    pub fn formatted_paths(&self, mode: ResultMode) -> Vec<PathBuf> {
+        self.entries()
+            .into_iter()
+            .map(|entry| {
+                let file_name = match &self.filter {
+                    Some(group) => group.apply_result_mode(&entry.file_name, mode),
+                    None => entry.file_name,
+                };
+                entry.file_path_name.with_file_name(file_name)
+            })
+            .collect()
+    }
+
+    /// Get selected files with their modification time and size.
+    ///
+    /// Unlike `files`, this touches the filesystem: it calls `std::fs::metadata` once per
+    /// entry. Files that don't exist or can't be queried get `None` for both fields.
+    pub fn entries_with_metadata(&self) -> Vec<SelectionMeta> {
+        self.files()
+            .into_iter()
+            .map(|path| {
+                let metadata = std::fs::metadata(&path).ok();
+                SelectionMeta {
+                    modified: metadata.as_ref().and_then(|m| m.modified().ok()),
+                    size: metadata.as_ref().map(|m| m.len()),
+                    path,
                 }
-            }
-        }
-        ret
+            })
+            .collect()
     }
 
     /// Get selected files as a HashMap of filename -> full path
     pub fn files_map(&self) -> HashMap<String, PathBuf> {
-        let mut map = HashMap::new();
-        for i in 0..self.ptr.count {
-            unsafe {
-                let filename = ptr_clone_to_string((*self.ptr.table.offset(i as isize)).fileName);
-                let file_path =
-                    ptr_clone_to_string((*self.ptr.table.offset(i as isize)).filePathName);
-                if !filename.is_empty() && !file_path.is_empty() {
-                    map.insert(filename, PathBuf::from(file_path));
+        self.files_ordered().into_iter().collect()
+    }
+
+    /// Get selected files as `(file name, full path)` pairs in a stable order.
+    ///
+    /// Unlike `files_map`, the pairs keep the order of the C selection table, which
+    /// ImGuiFileDialog sorts by file name (not by the order the files were clicked in).
+    pub fn files_ordered(&self) -> Vec<(String, PathBuf)> {
+        self.pairs()
+            .filter_map(|pair| unsafe {
+                let filename = ptr_clone_to_string(pair.fileName);
+                let file_path = ptr_clone_to_string(pair.filePathName);
+                (!filename.is_empty() && !file_path.is_empty())
+                    .then(|| (filename, PathBuf::from(file_path)))
+            })
+            .collect()
+    }
+
+    /// Count the selected files whose path matches `pred`, without collecting them
+    pub fn count_matching(&self, pred: impl Fn(&Path) -> bool) -> usize {
+        self.pairs()
+            .filter(|pair| {
+                if pair.filePathName.is_null() {
+                    return false;
                 }
-            }
-        }
-        map
+                let file_path = unsafe { CStr::from_ptr(pair.filePathName) }.to_string_lossy();
+                !file_path.is_empty() && pred(Path::new(file_path.as_ref()))
+            })
+            .count()
     }
 
     /// Get the number of selected files
@@ -62,7 +236,126 @@ impl<'ui> Selection<'ui> {
 impl Drop for Selection<'_> {
     fn drop(&mut self) {
         unsafe {
-            sys::IGFD_Selection_DestroyContent(&mut self.ptr);
+            backend::IGFD_Selection_DestroyContent(&mut self.ptr);
         }
     }
 }
+
+/// Serializes as a sequence of [`SelectionEntry`], e.g. a JSON array of
+/// `{"file_name": ..., "file_path_name": ...}` objects. Deserialize into a
+/// `Vec<SelectionEntry>` to read it back.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Selection<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.entries())
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+    use crate::test_support::headless;
+    use crate::util::matches_extension;
+    use crate::ResultMode;
+
+    #[test]
+    fn count_matching_by_extension() {
+        let mut imgui = headless();
+        crate::mock::reset();
+        let dialog = imgui.dialog("selection");
+        crate::mock::set_selection(&[
+            ("a.png", "/images/a.png"),
+            ("b.PNG", "/images/b.PNG"),
+            ("notes.txt", "/images/notes.txt"),
+            ("c.png", "/images/c.png"),
+        ]);
+
+        let selection = dialog.selection(ResultMode::default());
+        assert_eq!(selection.count(), 4);
+        assert_eq!(
+            selection.count_matching(|path| matches_extension(path, ".png", false)),
+            2
+        );
+        assert_eq!(
+            selection.count_matching(|path| matches_extension(path, ".png", true)),
+            3
+        );
+        assert_eq!(
+            selection.count_matching(|path| matches_extension(path, ".jpg", true)),
+            0
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialized_selection_deserializes_into_entries() {
+        use crate::selection::SelectionEntry;
+        use std::path::PathBuf;
+
+        let mut imgui = headless();
+        crate::mock::reset();
+        let dialog = imgui.dialog("selection");
+        crate::mock::set_selection(&[
+            ("a.png", "/images/a.png"),
+            ("notes, draft.txt", "/images/notes, draft.txt"),
+        ]);
+
+        let selection = dialog.selection(ResultMode::default());
+        let json = serde_json::to_string(&selection).unwrap();
+        let entries: Vec<SelectionEntry> = serde_json::from_str(&json).unwrap();
+        assert_eq!(entries, selection.entries());
+        assert_eq!(
+            entries[1],
+            SelectionEntry {
+                file_name: "notes, draft.txt".to_string(),
+                file_path_name: PathBuf::from("/images/notes, draft.txt"),
+            }
+        );
+    }
+
+    #[test]
+    fn files_ordered_keeps_the_table_order() {
+        use std::path::PathBuf;
+
+        let mut imgui = headless();
+        crate::mock::reset();
+        let dialog = imgui.dialog("selection");
+        crate::mock::set_selection(&[
+            ("c.txt", "/docs/c.txt"),
+            ("a.txt", "/docs/a.txt"),
+            ("b.txt", "/docs/b.txt"),
+        ]);
+
+        let selection = dialog.selection(ResultMode::KeepInputFile);
+        let names: Vec<_> = selection
+            .files_ordered()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        assert_eq!(names, ["c.txt", "a.txt", "b.txt"]);
+        assert_eq!(
+            selection.files_ordered()[0],
+            ("c.txt".to_string(), PathBuf::from("/docs/c.txt"))
+        );
+    }
+
+    #[test]
+    fn unique_files_drops_duplicates() {
+        use std::path::PathBuf;
+
+        let mut imgui = headless();
+        crate::mock::reset();
+        let dialog = imgui.dialog("selection");
+        crate::mock::set_selection(&[
+            ("b.txt", "/docs/b.txt"),
+            ("a.txt", "/docs/a.txt"),
+            ("b.txt", "/docs/b.txt"),
+        ]);
+
+        let selection = dialog.selection(ResultMode::KeepInputFile);
+        assert_eq!(selection.files().len(), 3);
+        assert_eq!(
+            selection.unique_files(),
+            [PathBuf::from("/docs/b.txt"), PathBuf::from("/docs/a.txt")]
+        );
+    }
+}
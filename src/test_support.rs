@@ -0,0 +1,40 @@
+// Helpers for the unit tests that need an ImGui context.
+
+use crate::FileDialog;
+use std::sync::{Mutex, MutexGuard};
+
+// imgui allows a single context per process, so tests needing one take turns
+static IMGUI: Mutex<()> = Mutex::new(());
+
+/// A headless ImGui context, exclusive to the test holding it
+pub(crate) struct Headless {
+    pub ctx: imgui::Context,
+    // declared last, so the context is dropped before the next test can create one
+    _lock: MutexGuard<'static, ()>,
+}
+
+pub(crate) fn headless() -> Headless {
+    // a failed test poisons the lock, which doesn't affect the others
+    let lock = IMGUI.lock().unwrap_or_else(|err| err.into_inner());
+    let mut ctx = imgui::Context::create();
+    ctx.io_mut().display_size = [800.0, 600.0];
+    ctx.fonts().build_rgba32_texture();
+    ctx.set_ini_filename(None);
+    Headless { ctx, _lock: lock }
+}
+
+impl Headless {
+    /// Create a dialog in a frame of its own
+    pub fn dialog(&mut self, id: &str) -> FileDialog {
+        let dialog = FileDialog::create(self.ctx.new_frame(), id);
+        self.ctx.render();
+        dialog
+    }
+
+    /// Run `f` within a new frame
+    pub fn frame<R>(&mut self, f: impl FnOnce(&imgui::Ui) -> R) -> R {
+        let result = f(self.ctx.new_frame());
+        self.ctx.render();
+        result
+    }
+}
@@ -1,6 +1,7 @@
 use imgui::sys::igMemFree;
 use std::ffi::CStr;
 use std::os::raw::c_void;
+use std::path::Path;
 
 /// Safe wrapper for converting C strings to Rust strings
 pub unsafe fn ptr_into_string(ptr: *mut std::os::raw::c_char) -> String {
@@ -21,3 +22,435 @@ pub unsafe fn ptr_clone_to_string(ptr: *const std::os::raw::c_char) -> String {
         CStr::from_ptr(ptr).to_string_lossy().into_owned()
     }
 }
+
+/// Run a Rust callback called from C++, where unwinding is undefined behavior.
+///
+/// Every trampoline handing control to user code goes through this. A panic is swallowed:
+/// the panic hook has already reported it, this adds which `callback` panicked, and
+/// `default` is returned to ImGuiFileDialog as the safest answer for that callback.
+pub(crate) fn ffi_guard<R: std::fmt::Debug>(
+    callback: &str,
+    default: R,
+    f: impl FnOnce() -> R,
+) -> R {
+    match std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)) {
+        Ok(result) => result,
+        Err(_) => {
+            eprintln!(
+                "imgui-filedialog: {} panicked, using {:?} instead",
+                callback, default
+            );
+            default
+        }
+    }
+}
+
+/// Check whether `path` has the extension `ext`, using the same rules as the dialog's filters.
+///
+/// `ext` is given with its leading dot (e.g. `.png`) and may span several levels
+/// (e.g. `.tar.gz`), in which case as many trailing dot-separated parts of the file name
+/// are compared. With `case_insensitive`, matching behaves like the dialog does under
+/// [`FileDialogFlags::CASE_INSENSITIVE_EXTENSION_FILTERING`](crate::FileDialogFlags::CASE_INSENSITIVE_EXTENSION_FILTERING).
+/// Wildcard and regex filters are not supported and never match.
+pub fn matches_extension(path: &Path, ext: &str, case_insensitive: bool) -> bool {
+    if ext.len() < 2 || !ext.starts_with('.') || ext.contains('*') || ext.contains("((") {
+        return false;
+    }
+    let file_name = match path.file_name() {
+        Some(name) => name.to_string_lossy(),
+        None => return false,
+    };
+
+    let levels = ext.matches('.').count();
+    let file_ext = match file_name.rmatch_indices('.').nth(levels - 1) {
+        Some((pos, _)) => &file_name[pos..],
+        None => return false,
+    };
+
+    if case_insensitive {
+        file_ext.to_lowercase() == ext.to_lowercase()
+    } else {
+        file_ext == ext
+    }
+}
+
+/// Check whether a file name has a non-empty extension.
+///
+/// A trailing dot doesn't count, matching how `ResultMode::AddIfNoFileExt` treats it.
+///
+/// ```
+/// use imgui_filedialog::has_extension;
+///
+/// assert!(!has_extension("report"));
+/// assert!(!has_extension("report."));
+/// assert!(has_extension("report.csv"));
+/// ```
+pub fn has_extension(file_name: &str) -> bool {
+    Path::new(file_name)
+        .extension()
+        .is_some_and(|ext| !ext.is_empty())
+}
+
+/// Convert a color given as 0-255 bytes to the 0-1 floats used for file style colors
+///
+/// ```
+/// assert_eq!(imgui_filedialog::rgba8(255, 0, 51, 255), [1.0, 0.0, 0.2, 1.0]);
+/// ```
+pub fn rgba8(r: u8, g: u8, b: u8, a: u8) -> [f32; 4] {
+    [r, g, b, a].map(|c| c as f32 / 255.0)
+}
+
+/// Shorten `s` to at most `max_chars` characters by replacing its middle with `…`
+///
+/// Counts `char`s, so a combining mark counts on its own and may be cut from its base.
+pub fn ellipsize_middle(s: &str, max_chars: usize) -> String {
+    let len = s.chars().count();
+    if len <= max_chars {
+        return s.to_string();
+    }
+    if max_chars == 0 {
+        return String::new();
+    }
+    let kept = max_chars - 1; // room for the ellipsis
+    let head = kept - kept / 2;
+    let tail = kept / 2;
+    let mut result: String = s.chars().take(head).collect();
+    result.push('…');
+    result.extend(s.chars().skip(len - tail));
+    result
+}
+
+/// Strip trailing separators from a directory path, keeping the one of a root such as
+/// `/` or `C:\`
+pub fn trim_trailing_separators(path: &str) -> &str {
+    let trimmed = path.trim_end_matches(std::path::is_separator);
+    if trimmed.is_empty() {
+        // `/` (or `//`) is the root itself
+        return &path[..path.len().min(1)];
+    }
+    if cfg!(windows) && trimmed.len() == 2 && trimmed.ends_with(':') && trimmed != path {
+        // `C:` alone would be relative to the drive's working directory
+        return &path[..3];
+    }
+    trimmed
+}
+
+/// Get the first component of a path: the drive (`C:\`) or UNC share (`\\server\share`)
+/// on Windows, the root directory otherwise. Relative paths yield their first component,
+/// e.g. `C:` for `C:x` or `a` for `a/b`.
+pub fn path_root(path: &str) -> Option<String> {
+    use std::path::{Component, Prefix};
+
+    let mut components = Path::new(path).components();
+    Some(match components.next()? {
+        Component::Prefix(prefix) => {
+            let mut root = prefix.as_os_str().to_string_lossy().into_owned();
+            // `C:` alone is relative to the drive's working directory, a share isn't
+            let disk = matches!(prefix.kind(), Prefix::Disk(_) | Prefix::VerbatimDisk(_));
+            if disk && components.next() == Some(Component::RootDir) {
+                root.push(std::path::MAIN_SEPARATOR);
+            }
+            root
+        }
+        Component::RootDir => std::path::MAIN_SEPARATOR.to_string(),
+        other => other.as_os_str().to_string_lossy().into_owned(),
+    })
+}
+
+/// Canonicalize a path that may not exist yet (e.g. a file to save).
+///
+/// The nearest existing ancestor is canonicalized and the rest joined onto it, with `..`
+/// removing the component before it; if no ancestor can be canonicalized, the path is
+/// returned unchanged.
+pub fn resolve_path(path: &Path) -> std::path::PathBuf {
+    use std::path::Component;
+
+    if let Ok(resolved) = path.canonicalize() {
+        return resolved;
+    }
+    match (path.parent(), path.components().next_back()) {
+        (Some(parent), Some(Component::ParentDir)) if !parent.as_os_str().is_empty() => {
+            let mut resolved = resolve_path(parent);
+            resolved.pop();
+            resolved
+        }
+        (Some(parent), Some(Component::Normal(name))) if !parent.as_os_str().is_empty() => {
+            resolve_path(parent).join(name)
+        }
+        _ => path.to_path_buf(),
+    }
+}
+
+/// Check whether `path` is `root` or inside of it, comparing them as resolved by
+/// `resolve_path`.
+pub fn is_within(path: &Path, root: &Path) -> bool {
+    resolve_path(path).starts_with(resolve_path(root))
+}
+
+/// Check whether `path` is a directory the current user may create files in.
+///
+/// Asks the OS without touching the directory: `access(2)` on Unix, which honors the
+/// user's permissions and read-only mounts, and the read-only attribute elsewhere.
+pub fn is_writable_dir(path: &Path) -> bool {
+    if !path.is_dir() {
+        return false;
+    }
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStrExt;
+        match std::ffi::CString::new(path.as_os_str().as_bytes()) {
+            Ok(path) => unsafe { libc::access(path.as_ptr(), libc::W_OK) == 0 },
+            Err(_) => false,
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        std::fs::metadata(path).is_ok_and(|metadata| !metadata.permissions().readonly())
+    }
+}
+
+/// Check whether a file can be created in the directory `path` by creating and removing
+/// an empty probe file
+pub fn probe_writable_dir(path: &Path) -> bool {
+    let probe = path.join(format!(".imgui-filedialog-probe-{}", std::process::id()));
+    let created = std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&probe)
+        .is_ok();
+    if created {
+        let _ = std::fs::remove_file(&probe);
+    }
+    created
+}
+
+/// List the logical drives (e.g. `C:\`) available on this machine
+#[cfg(windows)]
+pub fn logical_drives() -> Vec<String> {
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn GetLogicalDrives() -> u32;
+    }
+
+    let mask = unsafe { GetLogicalDrives() };
+    (0..26u8)
+        .filter(|i| mask & (1 << i) != 0)
+        .map(|i| format!("{}:\\", (b'A' + i) as char))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_extension_compares_the_extension() {
+        assert!(matches_extension(
+            Path::new("/data/image.png"),
+            ".png",
+            false
+        ));
+        assert!(matches_extension(Path::new("image.png"), ".png", false));
+        assert!(!matches_extension(
+            Path::new("/data/image.jpg"),
+            ".png",
+            false
+        ));
+        assert!(!matches_extension(Path::new("/data/png"), ".png", false));
+        assert!(!matches_extension(
+            Path::new("/data/image.png.bak"),
+            ".png",
+            false
+        ));
+        // wildcard and regex filters never match
+        assert!(!matches_extension(Path::new("image.png"), ".*", false));
+        assert!(!matches_extension(
+            Path::new("image.png"),
+            "((.*\\.png))",
+            false
+        ));
+    }
+
+    #[test]
+    fn matches_extension_folds_case_on_request() {
+        let path = Path::new("/data/IMAGE.PNG");
+        assert!(!matches_extension(path, ".png", false));
+        assert!(matches_extension(path, ".png", true));
+        assert!(matches_extension(path, ".PNG", false));
+        assert!(matches_extension(Path::new("photo.Png"), ".pNG", true));
+    }
+
+    #[test]
+    fn matches_extension_compares_multi_dot_extensions() {
+        let path = Path::new("backup.tar.gz");
+        assert!(matches_extension(path, ".tar.gz", false));
+        assert!(matches_extension(path, ".gz", false));
+        assert!(!matches_extension(Path::new("backup.gz"), ".tar.gz", false));
+        assert!(!matches_extension(
+            Path::new("backup.zip.gz"),
+            ".tar.gz",
+            true
+        ));
+        assert!(!matches_extension(
+            Path::new("backup.TAR.GZ"),
+            ".tar.gz",
+            false
+        ));
+        assert!(matches_extension(
+            Path::new("backup.TAR.Gz"),
+            ".tar.gz",
+            true
+        ));
+    }
+
+    #[test]
+    fn trim_trailing_separators_strips_repeated_separators() {
+        assert_eq!(trim_trailing_separators("/data/images/"), "/data/images");
+        assert_eq!(trim_trailing_separators("/data/images///"), "/data/images");
+        assert_eq!(trim_trailing_separators("/data/images"), "/data/images");
+        assert_eq!(trim_trailing_separators("images/"), "images");
+    }
+
+    #[test]
+    fn trim_trailing_separators_keeps_the_root() {
+        assert_eq!(trim_trailing_separators("/"), "/");
+        assert_eq!(trim_trailing_separators("///"), "/");
+        assert_eq!(trim_trailing_separators(""), "");
+    }
+
+    #[cfg(not(windows))]
+    #[test]
+    fn trim_trailing_separators_keeps_backslashes() {
+        // `\` is a valid file name character outside of Windows
+        assert_eq!(trim_trailing_separators("images\\"), "images\\");
+        assert_eq!(trim_trailing_separators("C:\\"), "C:\\");
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn trim_trailing_separators_handles_windows_paths() {
+        assert_eq!(trim_trailing_separators("C:\\data\\"), "C:\\data");
+        assert_eq!(trim_trailing_separators("C:\\data\\/\\"), "C:\\data");
+        assert_eq!(trim_trailing_separators("C:\\"), "C:\\");
+        assert_eq!(trim_trailing_separators("C:\\\\"), "C:\\");
+        assert_eq!(trim_trailing_separators("\\"), "\\");
+    }
+
+    #[test]
+    fn is_within_compares_whole_components() {
+        let base = std::env::temp_dir().join("imgui-filedialog-is-within");
+        let root = base.join("foo");
+        std::fs::create_dir_all(root.join("sub")).unwrap();
+        std::fs::create_dir_all(base.join("foobar")).unwrap();
+
+        assert!(is_within(&root, &root));
+        assert!(is_within(&root.join("sub"), &root));
+        // paths that don't exist yet, e.g. a file to save
+        assert!(is_within(&root.join("sub").join("new.txt"), &root));
+        assert!(is_within(&root.join("new").join("file.txt"), &root));
+        assert!(!is_within(&base.join("foobar"), &root));
+        assert!(!is_within(&base.join("foobar").join("new.txt"), &root));
+        assert!(!is_within(&base, &root));
+    }
+
+    #[test]
+    fn is_within_resolves_parent_components() {
+        let base = std::env::temp_dir().join("imgui-filedialog-is-within-parent");
+        let root = base.join("root");
+        std::fs::create_dir_all(root.join("sub")).unwrap();
+        std::fs::create_dir_all(base.join("outside")).unwrap();
+
+        assert!(is_within(&root.join("sub").join(".."), &root));
+        assert!(is_within(&root.join("sub").join("..").join("sub"), &root));
+        assert!(!is_within(&root.join(".."), &root));
+        assert!(!is_within(&root.join("..").join("outside"), &root));
+        assert!(!is_within(
+            &root.join("sub").join("..").join("..").join("outside"),
+            &root
+        ));
+        // `..` after a component that doesn't exist
+        let missing = root.join("missing");
+        assert!(is_within(&missing.join("..").join("new.txt"), &root));
+        assert!(!is_within(
+            &missing.join("..").join("..").join("outside"),
+            &root
+        ));
+        assert!(!is_within(
+            &missing.join("..").join("..").join("new.txt"),
+            &root
+        ));
+    }
+
+    #[test]
+    fn path_root_of_empty_and_relative_paths() {
+        assert_eq!(path_root(""), None);
+        assert_eq!(path_root("a/b").as_deref(), Some("a"));
+        assert_eq!(path_root("./a").as_deref(), Some("."));
+        assert_eq!(path_root("../a").as_deref(), Some(".."));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn path_root_on_unix() {
+        assert_eq!(path_root("/a/b").as_deref(), Some("/"));
+        assert_eq!(path_root("/").as_deref(), Some("/"));
+        // backslashes aren't separators on Unix
+        assert_eq!(path_root(r"C:\x").as_deref(), Some(r"C:\x"));
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn path_root_on_windows() {
+        assert_eq!(path_root(r"C:\x").as_deref(), Some(r"C:\"));
+        assert_eq!(path_root(r"C:\").as_deref(), Some(r"C:\"));
+        // drive relative
+        assert_eq!(path_root("C:x").as_deref(), Some("C:"));
+        assert_eq!(
+            path_root(r"\\server\share\dir\file.txt").as_deref(),
+            Some(r"\\server\share")
+        );
+        assert_eq!(path_root(r"\x").as_deref(), Some(r"\"));
+        assert_eq!(path_root(r"a\b").as_deref(), Some("a"));
+    }
+
+    #[test]
+    fn ellipsize_middle_edge_lengths() {
+        let s = "abcdef";
+        assert_eq!(ellipsize_middle(s, 0), "");
+        assert_eq!(ellipsize_middle(s, 1), "…");
+        assert_eq!(ellipsize_middle(s, 2), "a…");
+        assert_eq!(ellipsize_middle(s, 5), "ab…ef");
+        assert_eq!(ellipsize_middle(s, 6), "abcdef");
+        assert_eq!(ellipsize_middle("", 0), "");
+    }
+
+    #[test]
+    fn ellipsize_middle_keeps_whole_chars() {
+        // multi-byte chars, and `e` followed by a combining acute accent
+        for s in [
+            "/home/jörg/Bilder/日本語/ファイル",
+            "/cafe\u{301}/re\u{301}sume\u{301}",
+        ] {
+            let chars: Vec<char> = s.chars().collect();
+            let len = chars.len();
+            assert_eq!(ellipsize_middle(s, len), s);
+            assert_eq!(ellipsize_middle(s, len + 1), s);
+            for max_chars in 0..len {
+                let result = ellipsize_middle(s, max_chars);
+                let result_chars: Vec<char> = result.chars().collect();
+                assert!(result_chars.len() <= max_chars, "{:?}", result);
+                if max_chars == 0 {
+                    continue;
+                }
+                // the kept chars are whole chars from both ends of the input
+                let at = result_chars.iter().position(|c| *c == '…').unwrap();
+                assert_eq!(result_chars[..at], chars[..at]);
+                let tail = &result_chars[at + 1..];
+                assert_eq!(tail, &chars[len - tail.len()..]);
+                assert_eq!(result_chars.len(), max_chars);
+            }
+        }
+        assert_eq!(ellipsize_middle("日本語ファイル", 4), "日本…ル");
+        assert_eq!(ellipsize_middle("re\u{301}sume\u{301}", 4), "re…\u{301}");
+    }
+}